@@ -0,0 +1,56 @@
+"""Gradients of Python functions with many variables.
+
+Compares the evaluation with dynamically sized dual numbers (one call of the function,
+used by `gradient` for more than 16 variables) to chunks of statically sized dual
+numbers with 16 derivatives (one call of the function per chunk).
+
+Run with the Python package installed: `python benches/gradient.py`
+"""
+
+import timeit
+
+from num_dual import Dual64Dyn, DualSVec64_16
+
+CHUNK = 16
+
+
+def objective(x):
+    """Least-squares type objective function."""
+    s = 0.0
+    for i in range(len(x) - 1):
+        s = s + (x[i + 1] - x[i] * x[i]) ** 2 * 100.0 + (1.0 - x[i]) ** 2
+    return s + sum(xi.exp() for xi in x) * 1e-3
+
+
+def dynamic(x):
+    n = len(x)
+    x = [Dual64Dyn(xi, [float(i == j) for j in range(n)]) for i, xi in enumerate(x)]
+    res = objective(x)
+    return res.value, res.first_derivative
+
+
+def chunked(x):
+    grad = []
+    for start in range(0, len(x), CHUNK):
+        xc = [
+            DualSVec64_16.from_state([[xi], [float(i - start == j) for j in range(CHUNK)]])
+            for i, xi in enumerate(x)
+        ]
+        res = objective(xc)
+        grad.extend(res.first_derivative[: len(x) - start])
+    return res.value, grad
+
+
+if __name__ == "__main__":
+    for n in (16, 30, 50):
+        x = [0.5 + 0.01 * i for i in range(n)]
+        (f_dyn, g_dyn), (f_chk, g_chk) = dynamic(x), chunked(x)
+        assert abs(f_dyn - f_chk) < 1e-12
+        assert all(abs(a - b) < 1e-10 for a, b in zip(g_dyn, g_chk))
+        t_dyn = min(timeit.repeat(lambda: dynamic(x), number=20, repeat=5)) / 20
+        t_chk = min(timeit.repeat(lambda: chunked(x), number=20, repeat=5)) / 20
+        print(
+            f"n = {n:2d}  Dual64Dyn: {t_dyn * 1e3:6.3f} ms  "
+            f"DualSVec64_16 (chunked): {t_chk * 1e3:6.3f} ms  "
+            f"ratio: {t_chk / t_dyn:4.2f}"
+        )
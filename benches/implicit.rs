@@ -0,0 +1,103 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use nalgebra::SVector;
+use num_dual::*;
+use std::hint::black_box;
+
+const N: usize = 8;
+
+/// Residual of a discretized nonlinear boundary value problem.
+fn residual<D: DualNum<f64> + Copy>(x: SVector<D, N>, a: &D) -> SVector<D, N> {
+    SVector::from_fn(|i, _| {
+        let left = if i == 0 { D::zero() } else { x[i - 1] };
+        let right = if i == N - 1 { D::one() } else { x[i + 1] };
+        left - x[i] * 2.0 + right - *a * x[i].powi(3) * 0.01
+    })
+}
+
+fn solution(a: f64) -> SVector<f64, N> {
+    let mut x = SVector::from_fn(|i, _| (i + 1) as f64 / (N + 1) as f64);
+    for _ in 0..50 {
+        let (f, jac) = jacobian(|x| residual(x, &DualSVec64::from(a)), &x);
+        x -= jac.lu().solve(&f).unwrap();
+    }
+    x
+}
+
+fn scalar<D: DualNum<f64> + Copy>(x: D, a: &D) -> D {
+    x.powi(3) - *a * x - 1.0
+}
+
+fn binary<D: DualNum<f64> + Copy>(x: D, y: D, a: &D) -> [D; 2] {
+    [x * y - *a, x + y - *a - 1.0]
+}
+
+/// Potential with a stationary point at x_i = a^(i+1).
+fn potential<D: DualNum<f64> + Copy>(x: SVector<D, N>, a: &D) -> D {
+    (0..N)
+        .map(|i| {
+            let prev = if i == 0 { *a } else { x[i - 1] * *a };
+            (x[i] - prev).powi(2) + (x[i] - prev).powi(4)
+        })
+        .sum()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let x = solution(2.0);
+    let a = Dual3::from_re(Dual64::from(2.0).derivative()).derivative();
+    let b = Dual3::from_re(Dual3_64::from(2.0).derivative()).derivative();
+
+    let mut group = c.benchmark_group("Implicit derivative (scalar)");
+    // the golden ratio is a root of x³ - 2x - 1
+    let x0 = (1.0 + 5.0f64.sqrt()) * 0.5;
+    group.bench_function("Newton Dual3Dual64", |bench| {
+        bench.iter(|| implicit_derivative(scalar, black_box(x0), black_box(&a)))
+    });
+    group.bench_function("Taylor Dual3Dual64", |bench| {
+        bench.iter(|| implicit_derivative_taylor(scalar, scalar, black_box(x0), black_box(&a)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("Implicit derivative (binary)");
+    // x = 1, y = 2 solves xy = 2 and x + y = 3
+    group.bench_function("Newton Dual3Dual64", |bench| {
+        bench.iter(|| implicit_derivative_binary(binary, black_box(1.0), black_box(2.0), &a))
+    });
+    group.bench_function("Taylor Dual3Dual64", |bench| {
+        bench.iter(|| {
+            implicit_derivative_binary_taylor(binary, binary, black_box(1.0), black_box(2.0), &a)
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("Implicit derivative (vector)");
+    group.bench_function("Newton Dual3Dual64", |bench| {
+        bench.iter(|| implicit_derivative_vec(residual, black_box(x), black_box(&a)))
+    });
+    group.bench_function("Taylor Dual3Dual64", |bench| {
+        bench.iter(|| {
+            implicit_derivative_vec_taylor(residual, residual, black_box(x), black_box(&a))
+        })
+    });
+    group.bench_function("Newton Dual3Dual3_64", |bench| {
+        bench.iter(|| implicit_derivative_vec(residual, black_box(x), black_box(&b)))
+    });
+    group.bench_function("Taylor Dual3Dual3_64", |bench| {
+        bench.iter(|| {
+            implicit_derivative_vec_taylor(residual, residual, black_box(x), black_box(&b))
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("Implicit derivative (stationary point)");
+    let x = SVector::from_fn(|i, _| 2.0f64.powi(i as i32 + 1));
+    group.bench_function("Newton Dual3Dual64", |bench| {
+        bench.iter(|| implicit_derivative_sp(potential, black_box(x), black_box(&a)))
+    });
+    group.bench_function("Taylor Dual3Dual64", |bench| {
+        bench.iter(|| implicit_derivative_sp_taylor(potential, black_box(x), black_box(&a)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+use crate::{Variant, dual_parameter, is_type, mentions, replace_type, type_arguments};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::visit_mut::VisitMut;
+use syn::{DeriveInput, GenericParam, Result, Type, WherePredicate, parse_quote};
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let d = dual_parameter(&input.generics)?;
+    let has_f = input.generics.type_params().any(|p| p.ident == "F");
+    let real: Type = parse_quote!(F);
+    let inner: Type = parse_quote!(<#d as ::num_dual::DualStruct<#d, F>>::Inner);
+
+    // All bounds of the original type as where predicates.
+    let mut predicates: Vec<WherePredicate> = input
+        .generics
+        .type_params()
+        .filter(|p| !p.bounds.is_empty())
+        .map(|p| {
+            let (ident, bounds) = (&p.ident, &p.bounds);
+            parse_quote!(#ident: #bounds)
+        })
+        .collect();
+    if let Some(w) = &input.generics.where_clause {
+        predicates.extend(w.predicates.iter().cloned());
+    }
+
+    // The real and inner types need to satisfy the same bounds as the dual type.
+    let mut where_clause: Vec<TokenStream> = vec![
+        quote!(#d: ::num_dual::DualNum<F>),
+        quote!(F: ::num_dual::DualNumFloat),
+    ];
+    for p in &predicates {
+        where_clause.push(p.into_token_stream());
+        if !mentions(p, &d) {
+            continue;
+        }
+        for with in [&real, &inner] {
+            let p = replace_type(p, &d, with, |v, p| v.visit_where_predicate_mut(p));
+            where_clause.push(p.into_token_stream());
+        }
+    }
+
+    // Every field that depends on the dual type has to implement DualStruct itself.
+    let variants = Variant::all(&input)?;
+    for v in &variants {
+        for f in v.fields.iter() {
+            let ty = &f.ty;
+            if !mentions(ty, &d) || is_type(ty, &d) {
+                continue;
+            }
+            let ty_real = replace_type(ty, &d, &real, |v, t| v.visit_type_mut(t));
+            let ty_inner = replace_type(ty, &d, &inner, |v, t| v.visit_type_mut(t));
+            where_clause.push(quote! {
+                #ty: ::num_dual::DualStruct<#d, F, Real = #ty_real, Inner = #ty_inner>
+            });
+        }
+    }
+    let mut seen = Vec::new();
+    where_clause.retain(|p| {
+        let p = p.to_string();
+        let new = !seen.contains(&p);
+        seen.push(p);
+        new
+    });
+
+    let mut impl_generics = input.generics.clone();
+    impl_generics.where_clause = None;
+    if !has_f {
+        impl_generics.params.push(parse_quote!(F));
+    }
+    for p in impl_generics.params.iter_mut() {
+        match p {
+            GenericParam::Type(t) => {
+                t.bounds.clear();
+                t.eq_token = None;
+                t.default = None;
+            }
+            GenericParam::Const(c) => {
+                c.eq_token = None;
+                c.default = None;
+            }
+            GenericParam::Lifetime(_) => (),
+        }
+    }
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+    let ty_generics = type_arguments(&input.generics, &d, &d.to_token_stream());
+    let real_generics = type_arguments(&input.generics, &d, &real.to_token_stream());
+    let inner_generics = type_arguments(&input.generics, &d, &inner.to_token_stream());
+
+    let re_arms = variants
+        .iter()
+        .map(|v| {
+            v.arm(|ty, b| {
+                Ok(if mentions(ty, &d) {
+                    quote!(<#ty as ::num_dual::DualStruct<#d, F>>::re(#b))
+                } else {
+                    quote!(::core::clone::Clone::clone(#b))
+                })
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let from_inner_arms = variants
+        .iter()
+        .map(|v| {
+            v.arm(|ty, b| {
+                Ok(if mentions(ty, &d) {
+                    quote!(<#ty as ::num_dual::DualStruct<#d, F>>::from_inner(#b))
+                } else {
+                    quote!(::core::clone::Clone::clone(#b))
+                })
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::num_dual::DualStruct<#d, F> for #name #ty_generics
+        where
+            #(#where_clause,)*
+        {
+            type Real = #name #real_generics;
+            type Inner = #name #inner_generics;
+            fn re(&self) -> Self::Real {
+                match self {
+                    #(#re_arms,)*
+                }
+            }
+            fn from_inner(inner: &Self::Inner) -> Self {
+                match inner {
+                    #(#from_inner_arms,)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,243 @@
+//! Derive macros for the [`DualStruct`](https://docs.rs/num-dual/latest/num_dual/trait.DualStruct.html)
+//! and [`Mappable`](https://docs.rs/num-dual/latest/num_dual/trait.Mappable.html) traits of `num-dual`.
+//!
+//! The macros are re-exported by `num-dual` if the `derive` feature is enabled and should be used from there.
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericParam, Generics, Ident, Result, Type,
+    parse_macro_input,
+};
+
+mod dual_struct;
+mod mappable;
+
+/// Derive `DualStruct<D, F>` for a struct or enum that is generic over the dual number type.
+///
+/// The first type parameter of the type is interpreted as the dual number type `D`. The associated types
+/// `Real` and `Inner` are the same struct or enum with `D` replaced by the real type `F` and the inner
+/// type `D::Inner`, respectively. Every field that depends on `D` has to implement `DualStruct<D, F>`
+/// itself, all other fields are cloned. If the type has a type parameter called `F`, it is used as
+/// the real type, otherwise, the implementation is generic over `F: DualNumFloat`.
+/// ```
+/// use num_dual::{DualNum, DualStruct, Dual64, first_derivative, partial};
+///
+/// #[derive(DualStruct)]
+/// struct Parameters<D> {
+///     a: D,
+///     b: Vec<D>,
+///     name: String,
+/// }
+///
+/// fn f<D: DualNum<f64> + Copy>(x: D, p: &Parameters<D>) -> D {
+///     p.b.iter().fold(p.a * x, |acc, &b| acc * x + b)
+/// }
+///
+/// let p = Parameters { a: 1.0, b: vec![2.0, 3.0], name: "quadratic".into() };
+/// let (f, df) = first_derivative(partial(f, &p), 2.0);
+/// assert_eq!(f, 15.0);
+/// assert_eq!(df, 14.0);
+///
+/// let p = Parameters::<Dual64>::from_inner(&p);
+/// assert_eq!(p.re().b, vec![2.0, 3.0]);
+/// ```
+#[proc_macro_derive(DualStruct)]
+pub fn derive_dual_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    dual_struct::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive `Mappable<D>` for a struct or enum that is generic over the dual number type.
+///
+/// The first type parameter of the type is interpreted as the dual number type `D` and can not have
+/// any trait bounds. The output of the mapping is the same struct or enum with `D` replaced by the
+/// output type of the mapping. Fields of type `D` are mapped directly. Fields of types that contain `D`
+/// are mapped elementwise, if they are arrays, tuples, [`Vec`], [`Option`], [`Result`], [`Box`],
+/// [`HashMap`](std::collections::HashMap), or [`BTreeMap`](std::collections::BTreeMap), or
+/// via their own implementation of `Mappable` (e.g., another struct with derived `Mappable`). All other
+/// fields are moved into the output.
+/// ```
+/// use num_dual::{DualNum, Mappable, first_derivative};
+///
+/// #[derive(Mappable)]
+/// struct State<D> {
+///     pressure: D,
+///     composition: [D; 2],
+///     phase: &'static str,
+/// }
+///
+/// fn state<D: DualNum<f64> + Copy>(t: D) -> State<D> {
+///     State { pressure: t * 2.0, composition: [t.sin(), t.cos()], phase: "liquid" }
+/// }
+///
+/// let (p, dp) = first_derivative(state, 0.0).pressure;
+/// assert_eq!((p, dp), (0.0, 2.0));
+/// let [(x1, dx1), _] = first_derivative(state, 0.0).composition;
+/// assert_eq!((x1, dx1), (0.0, 1.0));
+/// ```
+///
+/// Fields that contain `nalgebra` matrices or vectors of `D` (e.g., `SVector<D, 2>`) are not supported,
+/// because the elements of a matrix have to be `nalgebra::Scalar`, which is not required for the output
+/// of the mapping. Such fields have to be stored as arrays or [`Vec`] instead.
+/// ```compile_fail
+/// use nalgebra::SVector;
+/// use num_dual::Mappable;
+///
+/// #[derive(Mappable)]
+/// struct State<D> {
+///     composition: SVector<D, 2>,
+/// }
+/// ```
+#[proc_macro_derive(Mappable)]
+pub fn derive_mappable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    mappable::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The first type parameter of the type, which is interpreted as the dual number type.
+fn dual_parameter(generics: &Generics) -> Result<Ident> {
+    generics
+        .type_params()
+        .next()
+        .map(|p| p.ident.clone())
+        .ok_or_else(|| {
+            Error::new_spanned(
+                generics,
+                "the type needs to have a type parameter for the dual number type",
+            )
+        })
+}
+
+/// The generic arguments of the type with the dual number type replaced by `with`.
+fn type_arguments(generics: &Generics, dual: &Ident, with: &TokenStream2) -> TokenStream2 {
+    let args = generics.params.iter().map(|p| match p {
+        GenericParam::Lifetime(l) => l.lifetime.to_token_stream(),
+        GenericParam::Type(t) if &t.ident == dual => with.clone(),
+        GenericParam::Type(t) => t.ident.to_token_stream(),
+        GenericParam::Const(c) => c.ident.to_token_stream(),
+    });
+    quote!(<#(#args),*>)
+}
+
+/// Check whether `ident` appears anywhere in `tokens`.
+fn mentions(tokens: &impl ToTokens, ident: &Ident) -> bool {
+    fn contains(stream: TokenStream2, ident: &Ident) -> bool {
+        stream.into_iter().any(|tt| match tt {
+            TokenTree::Ident(i) => &i == ident,
+            TokenTree::Group(g) => contains(g.stream(), ident),
+            _ => false,
+        })
+    }
+    contains(tokens.to_token_stream(), ident)
+}
+
+/// Check whether `ty` is exactly the type parameter `ident`.
+fn is_type(ty: &Type, ident: &Ident) -> bool {
+    matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident(ident))
+}
+
+/// Replace all occurrences of the type `ident` in a syntax tree by the type `with`.
+struct ReplaceType<'a> {
+    ident: &'a Ident,
+    with: &'a Type,
+}
+
+impl VisitMut for ReplaceType<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if is_type(ty, self.ident) {
+            *ty = self.with.clone();
+        } else {
+            visit_mut::visit_type_mut(self, ty);
+        }
+    }
+}
+
+fn replace_type<T: Clone>(
+    node: &T,
+    ident: &Ident,
+    with: &Type,
+    visit: impl FnOnce(&mut ReplaceType, &mut T),
+) -> T {
+    let mut node = node.clone();
+    visit(&mut ReplaceType { ident, with }, &mut node);
+    node
+}
+
+/// A single variant of a struct or enum, with bindings for all fields.
+struct Variant<'a> {
+    /// Path used to construct and destructure the variant (e.g., `Foo` or `Foo::Bar`).
+    path: TokenStream2,
+    fields: &'a Fields,
+}
+
+impl<'a> Variant<'a> {
+    fn all(input: &'a DeriveInput) -> Result<Vec<Self>> {
+        let name = &input.ident;
+        match &input.data {
+            Data::Struct(s) => Ok(vec![Variant {
+                path: quote!(#name),
+                fields: &s.fields,
+            }]),
+            Data::Enum(e) => Ok(e
+                .variants
+                .iter()
+                .map(|v| {
+                    let ident = &v.ident;
+                    Variant {
+                        path: quote!(#name::#ident),
+                        fields: &v.fields,
+                    }
+                })
+                .collect()),
+            Data::Union(_) => Err(Error::new_spanned(
+                input,
+                "unions are not supported by the derive macros of num-dual",
+            )),
+        }
+    }
+
+    fn binding(i: usize) -> Ident {
+        format_ident!("__binding_{}", i)
+    }
+
+    /// Pattern or constructor of the variant with the given expressions for every field.
+    fn construct(&self, exprs: impl IntoIterator<Item = TokenStream2>) -> TokenStream2 {
+        let path = &self.path;
+        let exprs = exprs.into_iter();
+        match self.fields {
+            Fields::Named(f) => {
+                let names = f.named.iter().map(|f| &f.ident);
+                quote!(#path { #(#names: #exprs),* })
+            }
+            Fields::Unnamed(_) => quote!(#path(#(#exprs),*)),
+            Fields::Unit => quote!(#path),
+        }
+    }
+
+    /// Pattern that binds all fields of the variant.
+    fn pattern(&self) -> TokenStream2 {
+        self.construct((0..self.fields.len()).map(|i| Self::binding(i).to_token_stream()))
+    }
+
+    /// Construct a match arm by transforming every field (type and binding) into an expression.
+    fn arm(
+        &self,
+        mut expr: impl FnMut(&Type, Ident) -> Result<TokenStream2>,
+    ) -> Result<TokenStream2> {
+        let pattern = self.pattern();
+        let exprs = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| expr(&f.ty, Self::binding(i)))
+            .collect::<Result<Vec<_>>>()?;
+        let construct = self.construct(exprs);
+        Ok(quote!(#pattern => #construct))
+    }
+}
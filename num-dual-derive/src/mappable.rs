@@ -0,0 +1,130 @@
+use crate::{Variant, dual_parameter, is_type, mentions, type_arguments};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Error, GenericArgument, Ident, PathArguments, Result, Type};
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let d = dual_parameter(&input.generics)?;
+    let bounded = input
+        .generics
+        .type_params()
+        .find(|p| p.ident == d)
+        .is_some_and(|p| !p.bounds.is_empty())
+        || input
+            .generics
+            .where_clause
+            .as_ref()
+            .is_some_and(|w| w.predicates.iter().any(|p| mentions(p, &d)));
+    if bounded {
+        return Err(Error::new_spanned(
+            &d,
+            "the dual number type of a type with derived `Mappable` can not have trait bounds",
+        ));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let output_generics = type_arguments(&input.generics, &d, &quote!(__O));
+
+    let arms = Variant::all(&input)?
+        .iter()
+        .map(|v| v.arm(|ty, b| map_expr(ty, quote!(#b), &d, 0)))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::num_dual::Mappable<#d> for #name #ty_generics #where_clause {
+            type Output<__O> = #name #output_generics;
+            fn map_dual<__M: Fn(#d) -> __O, __O>(self, f: __M) -> Self::Output<__O> {
+                let f = &f;
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// Expression that maps `expr` of type `ty` using the function `f`. `depth` is used to generate
+/// unique identifiers in nested closures.
+fn map_expr(ty: &Type, expr: TokenStream, d: &Ident, depth: usize) -> Result<TokenStream> {
+    if !mentions(ty, d) {
+        return Ok(expr);
+    }
+    if is_type(ty, d) {
+        return Ok(quote!(f(#expr)));
+    }
+    let x = format_ident!("__x{}", depth);
+    match ty {
+        Type::Paren(p) => map_expr(&p.elem, expr, d, depth),
+        Type::Group(g) => map_expr(&g.elem, expr, d, depth),
+        Type::Array(a) => {
+            let inner = map_expr(&a.elem, quote!(#x), d, depth + 1)?;
+            Ok(quote!(#expr.map(|#x| #inner)))
+        }
+        Type::Tuple(t) => {
+            let xs: Vec<_> = (0..t.elems.len())
+                .map(|i| format_ident!("__x{}_{}", depth, i))
+                .collect();
+            let inner = t
+                .elems
+                .iter()
+                .zip(&xs)
+                .map(|(ty, x)| map_expr(ty, quote!(#x), d, depth + 1))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(quote!({
+                let (#(#xs,)*) = #expr;
+                (#(#inner,)*)
+            }))
+        }
+        Type::Path(p) if p.qself.is_none() => {
+            let segment = p.path.segments.last().unwrap();
+            let args: Vec<_> = match &segment.arguments {
+                PathArguments::AngleBracketed(a) => a
+                    .args
+                    .iter()
+                    .filter_map(|a| match a {
+                        GenericArgument::Type(t) => Some(t),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            match (segment.ident.to_string().as_str(), args.as_slice()) {
+                ("Vec", [t]) => {
+                    let inner = map_expr(t, quote!(#x), d, depth + 1)?;
+                    Ok(quote!(#expr.into_iter().map(|#x| #inner).collect()))
+                }
+                ("Option", [t]) => {
+                    let inner = map_expr(t, quote!(#x), d, depth + 1)?;
+                    Ok(quote!(#expr.map(|#x| #inner)))
+                }
+                ("Box", [t]) => {
+                    let inner = map_expr(t, quote!(*#expr), d, depth + 1)?;
+                    Ok(quote!(::std::boxed::Box::new(#inner)))
+                }
+                ("Result", [t, e]) => {
+                    let inner = map_expr(t, quote!(#x), d, depth + 1)?;
+                    let err = map_expr(e, quote!(#x), d, depth + 1)?;
+                    Ok(quote!(#expr.map(|#x| #inner).map_err(|#x| #err)))
+                }
+                ("HashMap" | "BTreeMap", [k, v, ..]) if !mentions(k, d) => {
+                    let inner = map_expr(v, quote!(#x), d, depth + 1)?;
+                    Ok(quote!(#expr.into_iter().map(|(__k, #x)| (__k, #inner)).collect()))
+                }
+                (
+                    "Matrix" | "OMatrix" | "SMatrix" | "DMatrix" | "OVector" | "SVector"
+                    | "DVector" | "RowOVector" | "RowSVector" | "RowDVector",
+                    _,
+                ) => Err(Error::new_spanned(
+                    ty,
+                    "matrices can not be mapped by the `Mappable` derive macro, use arrays or `Vec` instead",
+                )),
+                _ => Ok(quote!(::num_dual::Mappable::map_dual(#expr, f))),
+            }
+        }
+        _ => Err(Error::new_spanned(
+            ty,
+            "fields of this type can not be mapped by the `Mappable` derive macro",
+        )),
+    }
+}
@@ -0,0 +1,1493 @@
+"""Type stubs for the num_dual extension module.
+
+Nested dual numbers are available for the outer types Dual, Dual2, Dual3, and
+HyperDual over the inner types Dual64, Dual2_64, Dual3_64, and HyperDual64 and
+over Dual64Dyn, Dual2_64Dyn, and HyperDual64Dyn. There are no nested dual
+numbers over 32-bit dual numbers or dual numbers with statically sized
+derivatives.
+"""
+
+from collections.abc import Callable, Sequence
+from typing import Any, Generic, Literal, TypeVar, overload
+
+import numpy as np
+from numpy.typing import NDArray
+from typing_extensions import Self
+
+__version__: str
+__all__: list[str]
+
+_F = TypeVar("_F")
+_Vector = Sequence[float] | NDArray[np.floating[Any]]
+
+class _DualNumber(Generic[_F]):
+    """Methods shared by all dual number types. Not available at runtime."""
+
+    @property
+    def value(self) -> _F: ...
+    def recip(self) -> Self: ...
+    def powi(self, n: int) -> Self: ...
+    def powf(self, n: float) -> Self: ...
+    def powd(self, n: Self | float) -> Self: ...
+    def sqrt(self) -> Self: ...
+    def cbrt(self) -> Self: ...
+    def exp(self) -> Self: ...
+    def exp2(self) -> Self: ...
+    def expm1(self) -> Self: ...
+    def log(self, base: Self | float | None = None) -> Self: ...
+    def log_base(self, base: Self | float) -> Self: ...
+    def log2(self) -> Self: ...
+    def log10(self) -> Self: ...
+    def log1p(self) -> Self: ...
+    def sin(self) -> Self: ...
+    def cos(self) -> Self: ...
+    def tan(self) -> Self: ...
+    def sin_cos(self) -> tuple[Self, Self]: ...
+    def arcsin(self) -> Self: ...
+    def arccos(self) -> Self: ...
+    def arctan(self) -> Self: ...
+    def sinh(self) -> Self: ...
+    def cosh(self) -> Self: ...
+    def tanh(self) -> Self: ...
+    def arcsinh(self) -> Self: ...
+    def arccosh(self) -> Self: ...
+    def arctanh(self) -> Self: ...
+    def sph_j0(self) -> Self: ...
+    def sph_j1(self) -> Self: ...
+    def sph_j2(self) -> Self: ...
+    def bessel_j0(self) -> Self: ...
+    def bessel_j1(self) -> Self: ...
+    def bessel_j2(self) -> Self: ...
+    def mul_add(self, a: Self | float, b: Self | float) -> Self: ...
+    def arctan2(self, other: Self | float) -> Self: ...
+    def atan2(self, other: Self | float) -> Self: ...
+    def hypot(self, other: Self | float) -> Self: ...
+    def __array_ufunc__(
+        self, ufunc: np.ufunc, method: str, *inputs: Any, **kwargs: Any
+    ) -> Any: ...
+    @overload
+    def __add__(self, value: Self | float, /) -> Self: ...
+    @overload
+    def __add__(self, value: NDArray[Any], /) -> NDArray[np.object_]: ...
+    def __radd__(self, value: float, /) -> Self: ...
+    @overload
+    def __sub__(self, value: Self | float, /) -> Self: ...
+    @overload
+    def __sub__(self, value: NDArray[Any], /) -> NDArray[np.object_]: ...
+    def __rsub__(self, value: float, /) -> Self: ...
+    @overload
+    def __mul__(self, value: Self | float, /) -> Self: ...
+    @overload
+    def __mul__(self, value: NDArray[Any], /) -> NDArray[np.object_]: ...
+    def __rmul__(self, value: float, /) -> Self: ...
+    @overload
+    def __truediv__(self, value: Self | float, /) -> Self: ...
+    @overload
+    def __truediv__(self, value: NDArray[Any], /) -> NDArray[np.object_]: ...
+    def __rtruediv__(self, value: float, /) -> Self: ...
+    def __pow__(self, value: Self | float, mod: None = None, /) -> Self: ...
+    def __rpow__(self, value: float, mod: None = None, /) -> Self: ...
+    def __floordiv__(self, value: Self | float, /) -> Self: ...
+    def __rfloordiv__(self, value: float, /) -> Self: ...
+    def __mod__(self, value: Self | float, /) -> Self: ...
+    def __rmod__(self, value: float, /) -> Self: ...
+    def __divmod__(self, value: Self | float, /) -> tuple[Self, Self]: ...
+    def __rdivmod__(self, value: float, /) -> tuple[Self, Self]: ...
+    def __lt__(self, value: Self | float, /) -> bool: ...
+    def __le__(self, value: Self | float, /) -> bool: ...
+    def __eq__(self, value: object, /) -> bool: ...
+    def __ne__(self, value: object, /) -> bool: ...
+    def __gt__(self, value: Self | float, /) -> bool: ...
+    def __ge__(self, value: Self | float, /) -> bool: ...
+    def __neg__(self) -> Self: ...
+    def __pos__(self) -> Self: ...
+    def __abs__(self) -> Self: ...
+    def __float__(self) -> float: ...
+    def __int__(self) -> int: ...
+    def __trunc__(self) -> int: ...
+    def __floor__(self) -> int: ...
+    def __ceil__(self) -> int: ...
+    @overload
+    def __round__(self, ndigits: None = None) -> int: ...
+    @overload
+    def __round__(self, ndigits: int) -> Self: ...
+    def __bool__(self) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getstate__(self) -> list[list[_F]]: ...
+    def __setstate__(self, state: list[list[_F]]) -> None: ...
+    def __reduce__(
+        self,
+    ) -> tuple[Callable[[list[list[_F]]], Self], tuple[list[list[_F]]]]: ...
+    def __copy__(self) -> Self: ...
+    def __deepcopy__(self, _memo: Any) -> Self: ...
+
+class Dual64(_DualNumber[float]):
+    """Dual number using 64-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual64: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual64: ...
+    @property
+    def first_derivative(self) -> float: ...
+
+class HyperDual64(_DualNumber[float]):
+    """Hyper dual number using 64-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps1: float, eps2: float, eps1eps2: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> HyperDual64: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDual64: ...
+    @property
+    def first_derivative(self) -> tuple[float, float]: ...
+    @property
+    def second_derivative(self) -> float: ...
+
+class Dual2_64(_DualNumber[float]):
+    """Second order dual number using 64-bit-floats as fields."""
+
+    def __new__(cls, eps: float, v1: float, v2: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual2_64: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2_64: ...
+    @property
+    def first_derivative(self) -> float: ...
+    @property
+    def second_derivative(self) -> float: ...
+
+class Dual3_64(_DualNumber[float]):
+    """Third order dual number using 64-bit-floats as fields."""
+
+    def __new__(cls, eps: float, v1: float, v2: float, v3: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual3_64: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual3_64: ...
+    @property
+    def first_derivative(self) -> float: ...
+    @property
+    def second_derivative(self) -> float: ...
+    @property
+    def third_derivative(self) -> float: ...
+
+class HyperDualDual64(_DualNumber[Dual64]):
+    """Hyper dual number using dual numbers as fields."""
+
+    def __new__(cls, re: Dual64, eps1: Dual64, eps2: Dual64, eps1eps2: Dual64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64) -> HyperDualDual64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64]]) -> HyperDualDual64: ...
+    @property
+    def first_derivative(self) -> tuple[Dual64, Dual64]: ...
+    @property
+    def second_derivative(self) -> Dual64: ...
+
+class Dual2Dual64(_DualNumber[Dual64]):
+    """Second order dual number using dual numbers as fields."""
+
+    def __new__(cls, v0: Dual64, v1: Dual64, v2: Dual64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64) -> Dual2Dual64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64]]) -> Dual2Dual64: ...
+    @property
+    def first_derivative(self) -> Dual64: ...
+    @property
+    def second_derivative(self) -> Dual64: ...
+
+class Dual3Dual64(_DualNumber[Dual64]):
+    """Third order dual number using dual numbers as fields."""
+
+    def __new__(cls, v0: Dual64, v1: Dual64, v2: Dual64, v3: Dual64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64) -> Dual3Dual64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64]]) -> Dual3Dual64: ...
+    @property
+    def first_derivative(self) -> Dual64: ...
+    @property
+    def second_derivative(self) -> Dual64: ...
+    @property
+    def third_derivative(self) -> Dual64: ...
+
+class HyperHyperDual64(_DualNumber[float]):
+    """Third order hyper dual number using 64-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps1: float, eps2: float, eps3: float, eps1eps2: float, eps1eps3: float, eps2eps3: float, eps1eps2eps3: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> HyperHyperDual64: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperHyperDual64: ...
+    @property
+    def first_derivative(self) -> tuple[float, float, float]: ...
+    @property
+    def second_derivative(self) -> tuple[float, float, float]: ...
+    @property
+    def third_derivative(self) -> float: ...
+
+class HyperHyperDual32(_DualNumber[float]):
+    """Third order hyper dual number using 32-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps1: float, eps2: float, eps3: float, eps1eps2: float, eps1eps3: float, eps2eps3: float, eps1eps2eps3: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> HyperHyperDual32: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperHyperDual32: ...
+    @property
+    def first_derivative(self) -> tuple[float, float, float]: ...
+    @property
+    def second_derivative(self) -> tuple[float, float, float]: ...
+    @property
+    def third_derivative(self) -> float: ...
+
+class Dual32(_DualNumber[float]):
+    """Dual number using 32-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual32: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual32: ...
+    @property
+    def first_derivative(self) -> float: ...
+
+class Dual2_32(_DualNumber[float]):
+    """Second order dual number using 32-bit-floats as fields."""
+
+    def __new__(cls, v0: float, v1: float, v2: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual2_32: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2_32: ...
+    @property
+    def first_derivative(self) -> float: ...
+    @property
+    def second_derivative(self) -> float: ...
+
+class Dual3_32(_DualNumber[float]):
+    """Third order dual number using 32-bit-floats as fields."""
+
+    def __new__(cls, v0: float, v1: float, v2: float, v3: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual3_32: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual3_32: ...
+    @property
+    def first_derivative(self) -> float: ...
+    @property
+    def second_derivative(self) -> float: ...
+    @property
+    def third_derivative(self) -> float: ...
+
+class HyperDual32(_DualNumber[float]):
+    """Hyper-dual number using 32-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps1: float, eps2: float, eps1eps2: float) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> HyperDual32: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDual32: ...
+    @property
+    def first_derivative(self) -> tuple[float, float]: ...
+    @property
+    def second_derivative(self) -> float: ...
+
+class DualDual64(_DualNumber[Dual64]):
+    """Dual number using dual numbers as fields."""
+
+    def __new__(cls, re: Dual64, eps: Dual64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64) -> DualDual64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64]]) -> DualDual64: ...
+    @property
+    def first_derivative(self) -> Dual64: ...
+
+class DualDual2_64(_DualNumber[Dual2_64]):
+    """Dual number using second order dual numbers as fields."""
+
+    def __new__(cls, re: Dual2_64, eps: Dual2_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64) -> DualDual2_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64]]) -> DualDual2_64: ...
+    @property
+    def first_derivative(self) -> Dual2_64: ...
+
+class Dual2Dual2_64(_DualNumber[Dual2_64]):
+    """Second order dual number using second order dual numbers as fields."""
+
+    def __new__(cls, v0: Dual2_64, v1: Dual2_64, v2: Dual2_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64) -> Dual2Dual2_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64]]) -> Dual2Dual2_64: ...
+    @property
+    def first_derivative(self) -> Dual2_64: ...
+    @property
+    def second_derivative(self) -> Dual2_64: ...
+
+class Dual3Dual2_64(_DualNumber[Dual2_64]):
+    """Third order dual number using second order dual numbers as fields."""
+
+    def __new__(cls, v0: Dual2_64, v1: Dual2_64, v2: Dual2_64, v3: Dual2_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64) -> Dual3Dual2_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64]]) -> Dual3Dual2_64: ...
+    @property
+    def first_derivative(self) -> Dual2_64: ...
+    @property
+    def second_derivative(self) -> Dual2_64: ...
+    @property
+    def third_derivative(self) -> Dual2_64: ...
+
+class HyperDualDual2_64(_DualNumber[Dual2_64]):
+    """Hyper-dual number using second order dual numbers as fields."""
+
+    def __new__(cls, re: Dual2_64, eps1: Dual2_64, eps2: Dual2_64, eps1eps2: Dual2_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64) -> HyperDualDual2_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64]]) -> HyperDualDual2_64: ...
+    @property
+    def first_derivative(self) -> tuple[Dual2_64, Dual2_64]: ...
+    @property
+    def second_derivative(self) -> Dual2_64: ...
+
+class DualDual3_64(_DualNumber[Dual3_64]):
+    """Dual number using third order dual numbers as fields."""
+
+    def __new__(cls, re: Dual3_64, eps: Dual3_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual3_64) -> DualDual3_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual3_64]]) -> DualDual3_64: ...
+    @property
+    def first_derivative(self) -> Dual3_64: ...
+
+class Dual2Dual3_64(_DualNumber[Dual3_64]):
+    """Second order dual number using third order dual numbers as fields."""
+
+    def __new__(cls, v0: Dual3_64, v1: Dual3_64, v2: Dual3_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual3_64) -> Dual2Dual3_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual3_64]]) -> Dual2Dual3_64: ...
+    @property
+    def first_derivative(self) -> Dual3_64: ...
+    @property
+    def second_derivative(self) -> Dual3_64: ...
+
+class Dual3Dual3_64(_DualNumber[Dual3_64]):
+    """Third order dual number using third order dual numbers as fields."""
+
+    def __new__(cls, v0: Dual3_64, v1: Dual3_64, v2: Dual3_64, v3: Dual3_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual3_64) -> Dual3Dual3_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual3_64]]) -> Dual3Dual3_64: ...
+    @property
+    def first_derivative(self) -> Dual3_64: ...
+    @property
+    def second_derivative(self) -> Dual3_64: ...
+    @property
+    def third_derivative(self) -> Dual3_64: ...
+
+class HyperDualDual3_64(_DualNumber[Dual3_64]):
+    """Hyper-dual number using third order dual numbers as fields."""
+
+    def __new__(cls, re: Dual3_64, eps1: Dual3_64, eps2: Dual3_64, eps1eps2: Dual3_64) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual3_64) -> HyperDualDual3_64: ...
+    @staticmethod
+    def from_state(state: list[list[Dual3_64]]) -> HyperDualDual3_64: ...
+    @property
+    def first_derivative(self) -> tuple[Dual3_64, Dual3_64]: ...
+    @property
+    def second_derivative(self) -> Dual3_64: ...
+
+class DualHyperDual64(_DualNumber[HyperDual64]):
+    """Dual number using hyper-dual numbers as fields."""
+
+    def __new__(cls, re: HyperDual64, eps: HyperDual64) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64) -> DualHyperDual64: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64]]) -> DualHyperDual64: ...
+    @property
+    def first_derivative(self) -> HyperDual64: ...
+
+class Dual2HyperDual64(_DualNumber[HyperDual64]):
+    """Second order dual number using hyper-dual numbers as fields."""
+
+    def __new__(cls, v0: HyperDual64, v1: HyperDual64, v2: HyperDual64) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64) -> Dual2HyperDual64: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64]]) -> Dual2HyperDual64: ...
+    @property
+    def first_derivative(self) -> HyperDual64: ...
+    @property
+    def second_derivative(self) -> HyperDual64: ...
+
+class Dual3HyperDual64(_DualNumber[HyperDual64]):
+    """Third order dual number using hyper-dual numbers as fields."""
+
+    def __new__(cls, v0: HyperDual64, v1: HyperDual64, v2: HyperDual64, v3: HyperDual64) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64) -> Dual3HyperDual64: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64]]) -> Dual3HyperDual64: ...
+    @property
+    def first_derivative(self) -> HyperDual64: ...
+    @property
+    def second_derivative(self) -> HyperDual64: ...
+    @property
+    def third_derivative(self) -> HyperDual64: ...
+
+class HyperDualHyperDual64(_DualNumber[HyperDual64]):
+    """Hyper-dual number using hyper-dual numbers as fields."""
+
+    def __new__(cls, re: HyperDual64, eps1: HyperDual64, eps2: HyperDual64, eps1eps2: HyperDual64) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64) -> HyperDualHyperDual64: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64]]) -> HyperDualHyperDual64: ...
+    @property
+    def first_derivative(self) -> tuple[HyperDual64, HyperDual64]: ...
+    @property
+    def second_derivative(self) -> HyperDual64: ...
+
+class DualDual64Dyn(_DualNumber[Dual64Dyn]):
+    """Dual number using dual numbers with a dynamic number of derivatives as fields."""
+
+    def __new__(cls, re: Dual64Dyn, eps: Dual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64Dyn) -> DualDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64Dyn]]) -> DualDual64Dyn: ...
+    @property
+    def first_derivative(self) -> Dual64Dyn: ...
+
+class Dual2Dual64Dyn(_DualNumber[Dual64Dyn]):
+    """Second order dual number using dual numbers with a dynamic number of derivatives as fields."""
+
+    def __new__(cls, v0: Dual64Dyn, v1: Dual64Dyn, v2: Dual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64Dyn) -> Dual2Dual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64Dyn]]) -> Dual2Dual64Dyn: ...
+    @property
+    def first_derivative(self) -> Dual64Dyn: ...
+    @property
+    def second_derivative(self) -> Dual64Dyn: ...
+
+class Dual3Dual64Dyn(_DualNumber[Dual64Dyn]):
+    """Third order dual number using dual numbers with a dynamic number of derivatives as fields."""
+
+    def __new__(cls, v0: Dual64Dyn, v1: Dual64Dyn, v2: Dual64Dyn, v3: Dual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64Dyn) -> Dual3Dual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64Dyn]]) -> Dual3Dual64Dyn: ...
+    @property
+    def first_derivative(self) -> Dual64Dyn: ...
+    @property
+    def second_derivative(self) -> Dual64Dyn: ...
+    @property
+    def third_derivative(self) -> Dual64Dyn: ...
+
+class HyperDualDual64Dyn(_DualNumber[Dual64Dyn]):
+    """Hyper-dual number using dual numbers with a dynamic number of derivatives as fields."""
+
+    def __new__(cls, re: Dual64Dyn, eps1: Dual64Dyn, eps2: Dual64Dyn, eps1eps2: Dual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual64Dyn) -> HyperDualDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual64Dyn]]) -> HyperDualDual64Dyn: ...
+    @property
+    def first_derivative(self) -> tuple[Dual64Dyn, Dual64Dyn]: ...
+    @property
+    def second_derivative(self) -> Dual64Dyn: ...
+
+class DualDual2_64Dyn(_DualNumber[Dual2_64Dyn]):
+    """Dual number using second order dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, re: Dual2_64Dyn, eps: Dual2_64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64Dyn) -> DualDual2_64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64Dyn]]) -> DualDual2_64Dyn: ...
+    @property
+    def first_derivative(self) -> Dual2_64Dyn: ...
+
+class Dual2Dual2_64Dyn(_DualNumber[Dual2_64Dyn]):
+    """Second order dual number using second order dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, v0: Dual2_64Dyn, v1: Dual2_64Dyn, v2: Dual2_64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64Dyn) -> Dual2Dual2_64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64Dyn]]) -> Dual2Dual2_64Dyn: ...
+    @property
+    def first_derivative(self) -> Dual2_64Dyn: ...
+    @property
+    def second_derivative(self) -> Dual2_64Dyn: ...
+
+class Dual3Dual2_64Dyn(_DualNumber[Dual2_64Dyn]):
+    """Third order dual number using second order dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, v0: Dual2_64Dyn, v1: Dual2_64Dyn, v2: Dual2_64Dyn, v3: Dual2_64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64Dyn) -> Dual3Dual2_64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64Dyn]]) -> Dual3Dual2_64Dyn: ...
+    @property
+    def first_derivative(self) -> Dual2_64Dyn: ...
+    @property
+    def second_derivative(self) -> Dual2_64Dyn: ...
+    @property
+    def third_derivative(self) -> Dual2_64Dyn: ...
+
+class HyperDualDual2_64Dyn(_DualNumber[Dual2_64Dyn]):
+    """Hyper-dual number using second order dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, re: Dual2_64Dyn, eps1: Dual2_64Dyn, eps2: Dual2_64Dyn, eps1eps2: Dual2_64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: Dual2_64Dyn) -> HyperDualDual2_64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[Dual2_64Dyn]]) -> HyperDualDual2_64Dyn: ...
+    @property
+    def first_derivative(self) -> tuple[Dual2_64Dyn, Dual2_64Dyn]: ...
+    @property
+    def second_derivative(self) -> Dual2_64Dyn: ...
+
+class DualHyperDual64Dyn(_DualNumber[HyperDual64Dyn]):
+    """Dual number using hyper-dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, re: HyperDual64Dyn, eps: HyperDual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64Dyn) -> DualHyperDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64Dyn]]) -> DualHyperDual64Dyn: ...
+    @property
+    def first_derivative(self) -> HyperDual64Dyn: ...
+
+class Dual2HyperDual64Dyn(_DualNumber[HyperDual64Dyn]):
+    """Second order dual number using hyper-dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, v0: HyperDual64Dyn, v1: HyperDual64Dyn, v2: HyperDual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64Dyn) -> Dual2HyperDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64Dyn]]) -> Dual2HyperDual64Dyn: ...
+    @property
+    def first_derivative(self) -> HyperDual64Dyn: ...
+    @property
+    def second_derivative(self) -> HyperDual64Dyn: ...
+
+class Dual3HyperDual64Dyn(_DualNumber[HyperDual64Dyn]):
+    """Third order dual number using hyper-dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, v0: HyperDual64Dyn, v1: HyperDual64Dyn, v2: HyperDual64Dyn, v3: HyperDual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64Dyn) -> Dual3HyperDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64Dyn]]) -> Dual3HyperDual64Dyn: ...
+    @property
+    def first_derivative(self) -> HyperDual64Dyn: ...
+    @property
+    def second_derivative(self) -> HyperDual64Dyn: ...
+    @property
+    def third_derivative(self) -> HyperDual64Dyn: ...
+
+class HyperDualHyperDual64Dyn(_DualNumber[HyperDual64Dyn]):
+    """Hyper-dual number using hyper-dual numbers with a dynamic number of variables as fields."""
+
+    def __new__(cls, re: HyperDual64Dyn, eps1: HyperDual64Dyn, eps2: HyperDual64Dyn, eps1eps2: HyperDual64Dyn) -> Self: ...
+    @staticmethod
+    def from_re(re: HyperDual64Dyn) -> HyperDualHyperDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[HyperDual64Dyn]]) -> HyperDualHyperDual64Dyn: ...
+    @property
+    def first_derivative(self) -> tuple[HyperDual64Dyn, HyperDual64Dyn]: ...
+    @property
+    def second_derivative(self) -> HyperDual64Dyn: ...
+
+# Dual numbers with statically and dynamically sized derivatives that are passed to the
+# functions in `gradient`, `jacobian`, `hessian` and `partial_hessian`. Only the dynamically
+# sized ones can be constructed directly.
+
+class DualSVec64_1(_DualNumber[float]):
+    """Dual number with 1 derivative and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_1: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_2(_DualNumber[float]):
+    """Dual number with 2 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_2: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_3(_DualNumber[float]):
+    """Dual number with 3 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_3: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_4(_DualNumber[float]):
+    """Dual number with 4 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_4: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_5(_DualNumber[float]):
+    """Dual number with 5 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_5: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_6(_DualNumber[float]):
+    """Dual number with 6 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_6: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_6: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_7(_DualNumber[float]):
+    """Dual number with 7 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_7: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_7: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_8(_DualNumber[float]):
+    """Dual number with 8 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_8: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_8: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_9(_DualNumber[float]):
+    """Dual number with 9 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_9: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_9: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_10(_DualNumber[float]):
+    """Dual number with 10 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_10: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_10: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_11(_DualNumber[float]):
+    """Dual number with 11 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_11: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_11: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_12(_DualNumber[float]):
+    """Dual number with 12 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_12: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_12: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_13(_DualNumber[float]):
+    """Dual number with 13 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_13: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_13: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_14(_DualNumber[float]):
+    """Dual number with 14 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_14: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_14: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_15(_DualNumber[float]):
+    """Dual number with 15 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_15: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_15: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class DualSVec64_16(_DualNumber[float]):
+    """Dual number with 16 derivatives and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> DualSVec64_16: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> DualSVec64_16: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class Dual2SVec64_1(_DualNumber[float]):
+    """Second order dual number with 1 variable and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_1: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_2(_DualNumber[float]):
+    """Second order dual number with 2 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_2: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_3(_DualNumber[float]):
+    """Second order dual number with 3 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_3: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_4(_DualNumber[float]):
+    """Second order dual number with 4 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_4: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_5(_DualNumber[float]):
+    """Second order dual number with 5 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_5: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_6(_DualNumber[float]):
+    """Second order dual number with 6 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_6: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_6: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_7(_DualNumber[float]):
+    """Second order dual number with 7 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_7: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_7: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_8(_DualNumber[float]):
+    """Second order dual number with 8 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_8: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_8: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_9(_DualNumber[float]):
+    """Second order dual number with 9 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_9: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_9: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_10(_DualNumber[float]):
+    """Second order dual number with 10 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_10: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_10: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_11(_DualNumber[float]):
+    """Second order dual number with 11 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_11: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_11: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_12(_DualNumber[float]):
+    """Second order dual number with 12 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_12: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_12: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_13(_DualNumber[float]):
+    """Second order dual number with 13 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_13: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_13: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_14(_DualNumber[float]):
+    """Second order dual number with 14 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_14: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_14: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_15(_DualNumber[float]):
+    """Second order dual number with 15 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_15: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_15: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual2SVec64_16(_DualNumber[float]):
+    """Second order dual number with 16 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> Dual2SVec64_16: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2SVec64_16: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_1_1(_DualNumber[float]):
+    """Hyper dual number with 1 and 1 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_1_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_1_1: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_1_2(_DualNumber[float]):
+    """Hyper dual number with 1 and 2 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_1_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_1_2: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_1_3(_DualNumber[float]):
+    """Hyper dual number with 1 and 3 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_1_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_1_3: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_1_4(_DualNumber[float]):
+    """Hyper dual number with 1 and 4 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_1_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_1_4: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_1_5(_DualNumber[float]):
+    """Hyper dual number with 1 and 5 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_1_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_1_5: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_2_1(_DualNumber[float]):
+    """Hyper dual number with 2 and 1 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_2_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_2_1: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_2_2(_DualNumber[float]):
+    """Hyper dual number with 2 and 2 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_2_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_2_2: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_2_3(_DualNumber[float]):
+    """Hyper dual number with 2 and 3 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_2_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_2_3: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_2_4(_DualNumber[float]):
+    """Hyper dual number with 2 and 4 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_2_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_2_4: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_2_5(_DualNumber[float]):
+    """Hyper dual number with 2 and 5 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_2_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_2_5: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_3_1(_DualNumber[float]):
+    """Hyper dual number with 3 and 1 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_3_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_3_1: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_3_2(_DualNumber[float]):
+    """Hyper dual number with 3 and 2 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_3_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_3_2: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_3_3(_DualNumber[float]):
+    """Hyper dual number with 3 and 3 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_3_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_3_3: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_3_4(_DualNumber[float]):
+    """Hyper dual number with 3 and 4 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_3_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_3_4: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_3_5(_DualNumber[float]):
+    """Hyper dual number with 3 and 5 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_3_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_3_5: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_4_1(_DualNumber[float]):
+    """Hyper dual number with 4 and 1 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_4_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_4_1: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_4_2(_DualNumber[float]):
+    """Hyper dual number with 4 and 2 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_4_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_4_2: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_4_3(_DualNumber[float]):
+    """Hyper dual number with 4 and 3 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_4_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_4_3: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_4_4(_DualNumber[float]):
+    """Hyper dual number with 4 and 4 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_4_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_4_4: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_4_5(_DualNumber[float]):
+    """Hyper dual number with 4 and 5 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_4_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_4_5: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_5_1(_DualNumber[float]):
+    """Hyper dual number with 5 and 1 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_5_1: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_5_1: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_5_2(_DualNumber[float]):
+    """Hyper dual number with 5 and 2 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_5_2: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_5_2: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_5_3(_DualNumber[float]):
+    """Hyper dual number with 5 and 3 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_5_3: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_5_3: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_5_4(_DualNumber[float]):
+    """Hyper dual number with 5 and 4 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_5_4: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_5_4: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDualSVec64_5_5(_DualNumber[float]):
+    """Hyper dual number with 5 and 5 variables and 64-bit-floats as fields."""
+
+    @staticmethod
+    def from_re(re: float) -> HyperDualSVec64_5_5: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDualSVec64_5_5: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class Dual64Dyn(_DualNumber[float]):
+    """Dual number with a dynamic number of derivatives and 64-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps: list[float]) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual64Dyn: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+
+class Dual2_64Dyn(_DualNumber[float]):
+    """Second order dual number with a dynamic number of variables and 64-bit-floats as fields."""
+
+    def __new__(cls, re: float, v1: list[float], v2: list[list[float]]) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> Dual2_64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> Dual2_64Dyn: ...
+    @property
+    def first_derivative(self) -> list[float] | None: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+class HyperDual64Dyn(_DualNumber[float]):
+    """Hyper-dual number with a dynamic number of variables and 64-bit-floats as fields."""
+
+    def __new__(cls, re: float, eps1: list[float], eps2: list[float], eps1eps2: list[list[float]]) -> Self: ...
+    @staticmethod
+    def from_re(re: float) -> HyperDual64Dyn: ...
+    @staticmethod
+    def from_state(state: list[list[float]]) -> HyperDual64Dyn: ...
+    @property
+    def first_derivative(self) -> tuple[list[float] | None, list[float] | None]: ...
+    @property
+    def second_derivative(self) -> list[list[float]] | None: ...
+
+_D = TypeVar(
+    "_D",
+    Dual64,
+    HyperDual64,
+    Dual2_64,
+    Dual3_64,
+    HyperDualDual64,
+    Dual2Dual64,
+    Dual3Dual64,
+    HyperHyperDual64,
+    Dual32,
+    HyperDual32,
+    Dual2_32,
+    Dual3_32,
+    HyperHyperDual32,
+)
+_N = TypeVar("_N", Dual64, Dual2_64, Dual3_64, HyperDual64, DualDual64, Dual32, HyperDual32)
+_S = TypeVar("_S", bound=_DualNumber[Any])
+
+def first_derivative(f: Callable[[Dual64], Dual64], x: float) -> tuple[float, float]:
+    """Calculate the first derivative of a scalar, univariate function."""
+
+@overload
+def first_derivative_batch(
+    f: Callable[[Dual64], Dual64 | float],
+    x: Sequence[float] | NDArray[np.floating[Any]],
+    vectorized: Literal[False] = False,
+) -> tuple[NDArray[np.float64], NDArray[np.float64]]: ...
+@overload
+def first_derivative_batch(
+    f: Callable[[NDArray[np.object_]], Any],
+    x: Sequence[float] | NDArray[np.floating[Any]],
+    vectorized: Literal[True],
+) -> tuple[NDArray[np.float64], NDArray[np.float64]]: ...
+@overload
+def gradient_batch(
+    f: Callable[[list[Any]], Any],
+    x: Sequence[Sequence[float]] | NDArray[np.floating[Any]],
+    vectorized: Literal[False] = False,
+) -> tuple[NDArray[np.float64], NDArray[np.float64]]: ...
+@overload
+def gradient_batch(
+    f: Callable[[list[NDArray[np.object_]]], Any],
+    x: Sequence[Sequence[float]] | NDArray[np.floating[Any]],
+    vectorized: Literal[True],
+) -> tuple[NDArray[np.float64], NDArray[np.float64]]: ...
+
+def second_derivative(
+    f: Callable[[Dual2_64], Dual2_64], x: float
+) -> tuple[float, float, float]:
+    """Calculate the second derivative of a scalar, univariate function."""
+
+def third_derivative(
+    f: Callable[[Dual3_64], Dual3_64], x: float
+) -> tuple[float, float, float, float]:
+    """Calculate the third derivative of a scalar, univariate function."""
+
+def gradient(
+    f: Callable[[list[Any]], Any], x: _Vector
+) -> tuple[float, NDArray[np.float64]]:
+    """Calculate the gradient of a scalar, multivariate function."""
+
+def jacobian(
+    f: Callable[[list[Any]], Sequence[Any]], x: _Vector
+) -> tuple[NDArray[np.float64], NDArray[np.float64]]:
+    """Calculate the Jacobian of a vector, multivariate function."""
+
+def hessian(
+    f: Callable[[list[Any]], Any], x: _Vector
+) -> tuple[float, NDArray[np.float64], NDArray[np.float64]]:
+    """Calculate the Hessian of a scalar, multivariate function."""
+
+def second_partial_derivative(
+    f: Callable[[HyperDual64, HyperDual64], HyperDual64], x: float, y: float
+) -> tuple[float, float, float, float]:
+    """Calculate the second partial derivative of a scalar, bivariate function."""
+
+def partial_hessian(
+    f: Callable[[list[Any], list[Any]], Any], x: _Vector, y: _Vector
+) -> tuple[float, NDArray[np.float64], NDArray[np.float64], NDArray[np.float64]]:
+    """Calculate the partial Hessian of a scalar function."""
+
+def third_partial_derivative(
+    f: Callable[[HyperHyperDual64, HyperHyperDual64, HyperHyperDual64], HyperHyperDual64],
+    x: float,
+    y: float,
+    z: float,
+) -> tuple[float, float, float, float, float, float, float, float]:
+    """Calculate the third partial derivative of a scalar, trivariate function."""
+
+def third_partial_derivative_vec(
+    f: Callable[[list[HyperHyperDual64]], HyperHyperDual64],
+    x: Sequence[float],
+    i: int,
+    j: int,
+    k: int,
+) -> tuple[float, float, float, float, float, float, float, float]:
+    """Calculate a third partial derivative of a scalar, multivariate function."""
+
+@overload
+def implicit_derivative(g: Callable[[_D, _D], _D], x: float, args: _D) -> _D: ...
+@overload
+def implicit_derivative(g: Callable[[Any, Any], Any], x: float, args: Any) -> Any: ...
+
+@overload
+def implicit_derivative_binary(
+    g: Callable[[_D, _D, _D], Sequence[_D]], x: float, y: float, args: _D
+) -> tuple[_D, _D]: ...
+@overload
+def implicit_derivative_binary(
+    g: Callable[[Any, Any, Any], Sequence[Any]], x: float, y: float, args: Any
+) -> tuple[Any, Any]: ...
+
+@overload
+def implicit_derivative_vec(
+    g: Callable[[list[_D], _D], Sequence[_D]], x: _Vector, args: _D
+) -> list[_D]: ...
+@overload
+def implicit_derivative_vec(
+    g: Callable[[list[Any], Any], Sequence[Any]], x: _Vector, args: Any
+) -> list[Any]: ...
+
+@overload
+def implicit_derivative_sp(
+    g: Callable[[list[Any], _N], Any], x: _Vector, args: _N
+) -> list[_N]: ...
+@overload
+def implicit_derivative_sp(
+    g: Callable[[list[Any], Any], Any], x: _Vector, args: Any
+) -> list[Any]: ...
+
+class ImplicitDerivative:
+    """Helper class that stores the parameters of an implicit function in dual and real form."""
+
+    def __new__(cls, g: Callable[..., Any], args: Any) -> ImplicitDerivative: ...
+    def residual(self, *x: Any) -> Any:
+        """Evaluate the residual with the real parts of the parameters."""
+    @overload
+    def implicit_derivative(self, x: float) -> Any: ...
+    @overload
+    def implicit_derivative(self, x: float, y: float) -> tuple[Any, Any]: ...
+    @overload
+    def implicit_derivative(self, x: _Vector) -> list[Any]: ...
+
+@overload
+def bessel_j0(x: float) -> float: ...
+@overload
+def bessel_j0(x: _S) -> _S: ...
+
+@overload
+def bessel_j1(x: float) -> float: ...
+@overload
+def bessel_j1(x: _S) -> _S: ...
+
+@overload
+def bessel_j2(x: float) -> float: ...
+@overload
+def bessel_j2(x: _S) -> _S: ...
+
+# `num_dual.linalg` is a submodule of the extension module. A single stub file cannot declare
+# submodules, so its functions are typed through a namespace that does not exist at runtime.
+class _Linalg:
+    @staticmethod
+    def solve(a: Any, b: Any) -> NDArray[np.object_]:
+        """Solve the linear system a x = b using an LU decomposition with partial pivoting."""
+    @staticmethod
+    def det(a: Any) -> Any:
+        """Calculate the determinant of a non-singular matrix."""
+    @staticmethod
+    def inv(a: Any) -> NDArray[np.object_]:
+        """Calculate the inverse of a matrix."""
+    @staticmethod
+    def smallest_ev(a: Any) -> tuple[Any, NDArray[np.object_]]:
+        """Calculate the smallest eigenvalue and the corresponding eigenvector of a symmetric matrix."""
+    @staticmethod
+    def eigh(a: Any, max_iter: int = 200) -> tuple[NDArray[np.object_], NDArray[np.object_]]:
+        """Calculate the eigenvalues and eigenvectors of a symmetric matrix."""
+
+linalg: _Linalg
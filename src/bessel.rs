@@ -1,66 +1,79 @@
 #![allow(clippy::excessive_precision)]
-use crate::DualNum;
-use num_traits::{Float, Zero};
+use crate::{DualNum, DualNumFloat};
 use std::f64::consts::{FRAC_2_PI, FRAC_PI_4};
 
 /// Implementation of bessel functions for double precision (hyper) dual numbers.
 pub trait BesselDual: DualNum<f64> + Copy {
     /// 0th order bessel function of the first kind
-    fn bessel_j0(mut self) -> Self {
-        if self.is_negative() {
-            self = -self;
-        }
-
-        if self.re() <= 5.0 {
-            let z = self * self;
-            if self.re() < 1.0e-5 {
-                return Self::one() - z / 4.0;
-            }
-
-            (z - DR1) * (z - DR2) * polevl(z, &RP0) / p1evl(z, &RQ0)
-        } else {
-            let w = self.recip() * 5.0;
-            let q = w * w;
-            let p = polevl(q, &PP0) / polevl(q, &PQ0);
-            let q = polevl(q, &QP0) / p1evl(q, &QQ0);
-            let (s, c) = (self - FRAC_PI_4).sin_cos();
-            let p = p * c - w * q * s;
-            p * (Self::from(FRAC_2_PI) / self).sqrt()
-        }
+    fn bessel_j0(self) -> Self {
+        bessel_j0(self)
     }
 
     /// 1st order bessel function of the first kind
     fn bessel_j1(self) -> Self {
-        let x = self.abs();
-        if x.re() <= 5.0 {
-            {
-                let z = self * self;
-                polevl(z, &RP1) / p1evl(z, &RQ1) * self * (z - Z1) * (z - Z2)
-            }
-        } else {
-            let x = self.abs();
-            let w = x.recip() * 5.0;
-            let z = w * w;
-            let p = polevl(z, &PP1) / polevl(z, &PQ1);
-            let q = polevl(z, &QP1) / p1evl(z, &QQ1);
-            let (s, c) = (x - 3.0 * FRAC_PI_4).sin_cos();
-            let p = p * c - w * q * s;
-            self.signum() * p * (Self::from(FRAC_2_PI) / x).sqrt()
-        }
+        bessel_j1(self)
     }
 
     /// 2nd order bessel function of the first kind
     fn bessel_j2(self) -> Self {
-        if self.re().is_zero() {
-            self * self / 8.0 * (self * self / 24.0 + 1.0)
-        } else {
-            self.bessel_j1() * 2.0 / self - self.bessel_j0()
-        }
+        bessel_j2(self)
     }
 }
 
 impl<T: DualNum<f64> + Copy> BesselDual for T {}
 
+/// 0th order bessel function of the first kind for arbitrary (hyper) dual numbers.
+pub(crate) fn bessel_j0<D: DualNum<F>, F: DualNumFloat>(mut x: D) -> D {
+    if x.is_negative() {
+        x = -x;
+    }
+
+    if x.re() <= float::<F>(5.0) {
+        let z = x.clone() * &x;
+        if x.re() < float::<F>(1.0e-5) {
+            return D::one() - z / float::<F>(4.0);
+        }
+
+        (z.clone() - float::<F>(DR1)) * (z.clone() - float::<F>(DR2)) * polevl(&z, &RP0)
+            / p1evl(&z, &RQ0)
+    } else {
+        let w = x.recip() * float::<F>(5.0);
+        let q = w.clone() * &w;
+        let p = polevl(&q, &PP0) / polevl(&q, &PQ0);
+        let q = polevl(&q, &QP0) / p1evl(&q, &QQ0);
+        let (s, c) = (x.clone() - float::<F>(FRAC_PI_4)).sin_cos();
+        let p = p * c - w * q * s;
+        p * (D::from(float::<F>(FRAC_2_PI)) / x).sqrt()
+    }
+}
+
+/// 1st order bessel function of the first kind for arbitrary (hyper) dual numbers.
+pub(crate) fn bessel_j1<D: DualNum<F>, F: DualNumFloat>(x: D) -> D {
+    let a = x.abs();
+    if a.re() <= float::<F>(5.0) {
+        let z = x.clone() * &x;
+        polevl(&z, &RP1) / p1evl(&z, &RQ1) * x * (z.clone() - float::<F>(Z1)) * (z - float::<F>(Z2))
+    } else {
+        let w = a.recip() * float::<F>(5.0);
+        let z = w.clone() * &w;
+        let p = polevl(&z, &PP1) / polevl(&z, &PQ1);
+        let q = polevl(&z, &QP1) / p1evl(&z, &QQ1);
+        let (s, c) = (a.clone() - float::<F>(3.0 * FRAC_PI_4)).sin_cos();
+        let p = p * c - w * q * s;
+        x.signum() * p * (D::from(float::<F>(FRAC_2_PI)) / a).sqrt()
+    }
+}
+
+/// 2nd order bessel function of the first kind for arbitrary (hyper) dual numbers.
+pub(crate) fn bessel_j2<D: DualNum<F>, F: DualNumFloat>(x: D) -> D {
+    if x.re().is_zero() {
+        let x2 = x.clone() * x;
+        x2.clone() / float::<F>(8.0) * (x2 / float::<F>(24.0) + F::one())
+    } else {
+        bessel_j1(x.clone()) * float::<F>(2.0) / &x - bessel_j0(x)
+    }
+}
+
 const DR1: f64 = 5.78318596294678452118E0;
 const DR2: f64 = 3.04712623436620863991E1;
 
@@ -179,12 +192,19 @@ const QQ1: [f64; 7] = [
     3.36093607810698293419E2,
 ];
 
-fn polevl<T: DualNum<F> + Copy, F: Float>(x: T, coef: &[F]) -> T {
+fn float<F: DualNumFloat>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+fn polevl<D: DualNum<F>, F: DualNumFloat>(x: &D, coef: &[f64]) -> D {
     coef.iter()
         .skip(1)
-        .fold(T::from(coef[0]), |acc, &c| acc * x + c)
+        .fold(D::from(float::<F>(coef[0])), |acc, &c| {
+            acc * x + float::<F>(c)
+        })
 }
 
-fn p1evl<T: DualNum<F> + Copy, F: Float>(x: T, coef: &[F]) -> T {
-    coef.iter().fold(T::one(), |acc, &c| acc * x + c)
+fn p1evl<D: DualNum<F>, F: DualNumFloat>(x: &D, coef: &[f64]) -> D {
+    coef.iter()
+        .fold(D::one(), |acc, &c| acc * x + float::<F>(c))
 }
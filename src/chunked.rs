@@ -0,0 +1,171 @@
+use crate::*;
+use nalgebra::{Const, DMatrix, DVector, Dyn, OVector, U1};
+
+/// Chunk size used by [`Chunked`] if none is specified explicitly.
+pub const DEFAULT_CHUNK_SIZE: usize = 8;
+
+/// Chunked forward mode for the evaluation of gradients, Jacobians, and Hessians with respect to dynamically
+/// sized variable vectors.
+///
+/// Instead of evaluating one scalar sweep per direction (like the implementation of [`Gradients`] for
+/// [`Dyn`]) or using heap-allocated dual numbers (like [`DualDVec64`]), the directions are processed
+/// in blocks of `CHUNK` variables using the statically sized [`DualSVec`] and [`HyperDualVec`] types.
+/// A gradient or Jacobian with `n` variables requires `⌈n/CHUNK⌉` function evaluations, a Hessian
+/// requires one evaluation for every pair of blocks on and above the diagonal.
+///
+/// The chunk size is a compile-time constant. Larger chunks reduce the number of function evaluations but
+/// increase the cost of every operation. The default ([`DEFAULT_CHUNK_SIZE`]) is a reasonable compromise
+/// for most problems.
+/// ```
+/// # use num_dual::{Chunked, DualNum};
+/// # use nalgebra::{DVector, dvector};
+/// # use approx::assert_relative_eq;
+/// fn foo<D: DualNum<f64> + Copy>(x: DVector<D>, n: &D) -> D {
+///     x.dot(&x).sqrt() - n
+/// }
+///
+/// let x = dvector![1.0, 5.0, 5.0, 7.0];
+/// let (f, grad) = Chunked::<3>::gradient(foo, &x, &10.0);
+/// assert_eq!(f, 0.0);
+/// assert_relative_eq!(grad, dvector![0.1, 0.5, 0.5, 0.7]);
+///
+/// let (f, grad, hess) = <Chunked>::hessian(foo, &x, &10.0);
+/// assert_eq!(f, 0.0);
+/// assert_relative_eq!(grad, dvector![0.1, 0.5, 0.5, 0.7]);
+/// assert_relative_eq!(hess[(1, 2)], -0.025);
+/// ```
+pub struct Chunked<const CHUNK: usize = DEFAULT_CHUNK_SIZE>;
+
+impl<const CHUNK: usize> Chunked<CHUNK> {
+    const CHUNK_IS_POSITIVE: () = assert!(CHUNK > 0, "the chunk size has to be positive");
+
+    /// Calculate the gradient of a scalar function.
+    pub fn gradient<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        A: DualStruct<DualSVec<T, F, CHUNK>, F>,
+    >(
+        g: G,
+        x: &DVector<T>,
+        args: &A::Inner,
+    ) -> (T, DVector<T>)
+    where
+        G: Fn(DVector<DualSVec<T, F, CHUNK>>, &A) -> DualSVec<T, F, CHUNK>,
+    {
+        let () = Self::CHUNK_IS_POSITIVE;
+        let n = x.len();
+        let args = A::from_inner(args);
+        if n == 0 {
+            return (g(x.map(DualSVec::from_re), &args).re, DVector::zeros(0));
+        }
+        let mut re = T::zero();
+        let mut grad = DVector::zeros(n);
+        for start in (0..n).step_by(CHUNK) {
+            let res = g(Self::seed(x, start), &args);
+            re = res.re;
+            let eps = res.eps.unwrap_generic(Const, U1);
+            for (k, gi) in grad.iter_mut().skip(start).take(CHUNK).enumerate() {
+                *gi = eps[k];
+            }
+        }
+        (re, grad)
+    }
+
+    /// Calculate the (rectangular) Jacobian of a vector function.
+    pub fn jacobian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<DualSVec<T, F, CHUNK>, F>,
+    >(
+        g: G,
+        x: &DVector<T>,
+        args: &A::Inner,
+    ) -> (OVector<T, M>, OMatrix<T, M, Dyn>)
+    where
+        G: Fn(DVector<DualSVec<T, F, CHUNK>>, &A) -> OVector<DualSVec<T, F, CHUNK>, M>,
+        DefaultAllocator: Allocator<M> + Allocator<M, Dyn>,
+    {
+        let () = Self::CHUNK_IS_POSITIVE;
+        let n = x.len();
+        let args = A::from_inner(args);
+        let mut f = None;
+        let blocks: Vec<_> = (0..n)
+            .step_by(CHUNK)
+            .map(|start| {
+                let res = g(Self::seed(x, start), &args);
+                f = Some(res.map(|r| r.re));
+                res.map(|r| r.eps.unwrap_generic(Const::<CHUNK>, U1))
+            })
+            .collect();
+        let f = f.unwrap_or_else(|| g(x.map(DualSVec::from_re), &args).map(|r| r.re));
+        let (m, _) = f.shape_generic();
+        let jac = OMatrix::from_fn_generic(m, Dyn(n), |i, j| blocks[j / CHUNK][i][j % CHUNK]);
+        (f, jac)
+    }
+
+    /// Calculate the Hessian of a scalar function.
+    pub fn hessian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        A: DualStruct<HyperDualVec<T, F, Const<CHUNK>, Const<CHUNK>>, F>,
+    >(
+        g: G,
+        x: &DVector<T>,
+        args: &A::Inner,
+    ) -> (T, DVector<T>, DMatrix<T>)
+    where
+        G: Fn(
+            DVector<HyperDualVec<T, F, Const<CHUNK>, Const<CHUNK>>>,
+            &A,
+        ) -> HyperDualVec<T, F, Const<CHUNK>, Const<CHUNK>>,
+    {
+        let () = Self::CHUNK_IS_POSITIVE;
+        let n = x.len();
+        let args = A::from_inner(args);
+        if n == 0 {
+            let re = g(x.map(HyperDualVec::from_re), &args).re;
+            return (re, DVector::zeros(0), DMatrix::zeros(0, 0));
+        }
+        let mut re = T::zero();
+        let mut grad = DVector::zeros(n);
+        let mut hessian = DMatrix::zeros(n, n);
+        for start1 in (0..n).step_by(CHUNK) {
+            for start2 in (start1..n).step_by(CHUNK) {
+                let mut x = x.map(HyperDualVec::from_re);
+                for (k, xi) in x.iter_mut().skip(start1).take(CHUNK).enumerate() {
+                    xi.eps1 = Derivative::derivative_generic(Const, U1, k);
+                }
+                for (k, xi) in x.iter_mut().skip(start2).take(CHUNK).enumerate() {
+                    xi.eps2 = Derivative::derivative_generic(U1, Const, k);
+                }
+                let res = g(x, &args);
+                re = res.re;
+                let eps1 = res.eps1.unwrap_generic(Const::<CHUNK>, U1);
+                let eps1eps2 = res.eps1eps2.unwrap_generic(Const::<CHUNK>, Const::<CHUNK>);
+                for i in 0..CHUNK.min(n - start1) {
+                    grad[start1 + i] = eps1[i];
+                    for j in 0..CHUNK.min(n - start2) {
+                        hessian[(start1 + i, start2 + j)] = eps1eps2[(i, j)];
+                        hessian[(start2 + j, start1 + i)] = eps1eps2[(i, j)];
+                    }
+                }
+            }
+        }
+        (re, grad, hessian)
+    }
+
+    fn seed<T: DualNum<F> + Copy, F: DualNumFloat>(
+        x: &DVector<T>,
+        start: usize,
+    ) -> DVector<DualSVec<T, F, CHUNK>> {
+        let mut x = x.map(DualSVec::from_re);
+        for (k, xi) in x.iter_mut().skip(start).take(CHUNK).enumerate() {
+            xi.eps = Derivative::derivative_generic(Const, U1, k);
+        }
+        x
+    }
+}
@@ -38,6 +38,36 @@ where
         Self::new(None)
     }
 
+    /// Elements of the derivative in column-major order (empty if the derivative is `None`).
+    pub(crate) fn to_vec(&self) -> Vec<T> {
+        self.0
+            .as_ref()
+            .map_or_else(Vec::new, |m| m.as_slice().to_vec())
+    }
+
+    /// Shape of the derivative. Dynamic dimensions of a `None` derivative are reported as 0.
+    pub(crate) fn shape(&self) -> (usize, usize) {
+        self.0.as_ref().map_or_else(
+            || {
+                (
+                    R::try_to_usize().unwrap_or(0),
+                    C::try_to_usize().unwrap_or(0),
+                )
+            },
+            |m| m.shape(),
+        )
+    }
+
+    /// Derivative from elements in column-major order (`None` if `v` is empty).
+    pub(crate) fn from_slice(rows: usize, cols: usize, v: &[T]) -> Self {
+        if v.is_empty() {
+            Self::none()
+        } else {
+            let (r, c) = (R::from_usize(rows), C::from_usize(cols));
+            Self::some(OMatrix::from_column_slice_generic(r, c, v))
+        }
+    }
+
     pub(crate) fn map<T2, F2>(&self, f: impl FnMut(T) -> T2) -> Derivative<T2, F2, R, C>
     where
         T2: DualNum<F2>,
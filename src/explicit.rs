@@ -74,6 +74,22 @@ where
     g(x).map_dual(|r| r.re)
 }
 
+/// Separation of the result of [`first_derivative`] into the function value and the derivative.
+///
+/// Implemented for [`Dual`] numbers and (with the `ndarray` feature) arrays of [`Dual`] numbers, for
+/// which the values and derivatives are returned as separate arrays.
+pub trait FirstDerivative<T, F> {
+    type Output;
+    fn value_and_derivative(self) -> Self::Output;
+}
+
+impl<T: DualNum<F>, F: DualNumFloat> FirstDerivative<T, F> for Dual<T, F> {
+    type Output = (T, T);
+    fn value_and_derivative(self) -> (T, T) {
+        (self.re, self.eps)
+    }
+}
+
 /// Calculate the first derivative of a scalar function.
 /// ```
 /// # use num_dual::{first_derivative, DualNum};
@@ -81,15 +97,44 @@ where
 /// assert_eq!(f, 25.0);
 /// assert_eq!(df, 10.0);
 /// ```
-pub fn first_derivative<G, T: DualNum<F>, F: DualNumFloat, O: Mappable<Dual<T, F>>>(
+pub fn first_derivative<
+    G,
+    T: DualNum<F>,
+    F: DualNumFloat,
+    R: FirstDerivative<T, F>,
+    O: Mappable<R>,
+>(
     g: G,
     x: T,
-) -> O::Output<(T, T)>
+) -> O::Output<R::Output>
 where
     G: Fn(Dual<T, F>) -> O,
 {
     let x = Dual::from_re(x).derivative();
-    g(x).map_dual(|r| (r.re, r.eps))
+    g(x).map_dual(R::value_and_derivative)
+}
+
+/// Separation of the result of [`gradient`] into the function value and the gradient.
+///
+/// Implemented for [`DualVec`] numbers and (with the `ndarray` feature) arrays of [`DualVec`] numbers,
+/// which are separated elementwise. The dimension `n` of the variable vector is required because the
+/// derivative of a result that does not depend on the variables is not initialized.
+pub trait Gradient<T, F, D: Dim>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    type Output;
+    fn value_and_gradient(self, n: D) -> Self::Output;
+}
+
+impl<T: DualNum<F>, F: DualNumFloat, D: Dim> Gradient<T, F, D> for DualVec<T, F, D>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    type Output = (T, OVector<T, D>);
+    fn value_and_gradient(self, n: D) -> (T, OVector<T, D>) {
+        (self.re, self.eps.unwrap_generic(n, U1))
+    }
 }
 
 /// Calculate the gradient of a scalar function
@@ -119,10 +164,10 @@ where
 /// assert_relative_eq!(g[2], 0.5);
 /// assert_relative_eq!(g[3], 0.5);
 /// ```
-pub fn gradient<G, T: DualNum<F>, F: DualNumFloat, D: Dim, O: Mappable<DualVec<T, F, D>>>(
+pub fn gradient<G, T: DualNum<F>, F: DualNumFloat, D: Dim, R: Gradient<T, F, D>, O: Mappable<R>>(
     g: G,
     x: &OVector<T, D>,
-) -> O::Output<(T, OVector<T, D>)>
+) -> O::Output<R::Output>
 where
     G: Fn(OVector<DualVec<T, F, D>, D>) -> O,
     DefaultAllocator: Allocator<D>,
@@ -132,7 +177,7 @@ where
     for (i, xi) in x.iter_mut().enumerate() {
         xi.eps = Derivative::derivative_generic(r, c, i);
     }
-    g(x).map_dual(|res| (res.re, res.eps.unwrap_generic(r, c)))
+    g(x).map_dual(|res| res.value_and_gradient(r))
 }
 
 /// Calculate the Jacobian of a vector function.
@@ -456,8 +501,8 @@ where
     })
 }
 
-/// Evaluation of gradients, hessians, and partial (Nx1) hessians that is generic over the dimensionality
-/// of the input vector.
+/// Evaluation of gradients, hessians, partial (Nx1) hessians, and (rectangular) Jacobians that is generic
+/// over the dimensionality of the input vector.
 pub trait Gradients: Dim
 where
     DefaultAllocator: Allocator<Self>,
@@ -501,14 +546,45 @@ where
             &A,
         ) -> Self::HyperDual<T, F>;
 
-    fn jacobian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Self::Dual<T, F>, F>>(
+    /// Calculate the Jacobian of a vector function. The number of outputs `M` is independent of
+    /// the number of variables, so rectangular Jacobians (e.g., of least-squares residuals) can
+    /// be evaluated.
+    /// ```
+    /// # use num_dual::{DualNum, Gradients};
+    /// # use nalgebra::{OVector, DefaultAllocator, allocator::Allocator, U3, vector, dvector};
+    /// # use approx::assert_relative_eq;
+    /// fn residuals<D: DualNum<f64> + Copy, N: Gradients>(p: OVector<D, N>, t: &[D; 3]) -> OVector<D, U3>
+    /// where
+    ///     DefaultAllocator: Allocator<N>,
+    /// {
+    ///     OVector::<D, U3>::from(t.map(|t| p[0] * (p[1] * t).exp()))
+    /// }
+    ///
+    /// let t = [0.0, 1.0, 2.0];
+    /// let (f, jac) = Gradients::jacobian(residuals, &vector![2.0, 0.0], &t);
+    /// assert_relative_eq!(f, vector![2.0, 2.0, 2.0]);
+    /// assert_eq!(jac.shape(), (3, 2));
+    /// assert_relative_eq!(jac.column(1).into_owned(), vector![0.0, 2.0, 4.0]);
+    ///
+    /// let (f, jac) = Gradients::jacobian(residuals, &dvector![2.0, 0.0], &t);
+    /// assert_relative_eq!(f, vector![2.0, 2.0, 2.0]);
+    /// assert_eq!(jac.shape(), (3, 2));
+    /// assert_relative_eq!(jac.column(1).into_owned(), vector![0.0, 2.0, 4.0]);
+    /// ```
+    fn jacobian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<Self::Dual<T, F>, F>,
+    >(
         g: G,
         x: &OVector<T, Self>,
         args: &A::Inner,
-    ) -> (OVector<T, Self>, OMatrix<T, Self, Self>)
+    ) -> (OVector<T, M>, OMatrix<T, M, Self>)
     where
-        G: Fn(OVector<Self::Dual<T, F>, Self>, &A) -> OVector<Self::Dual<T, F>, Self>,
-        DefaultAllocator: Allocator<Self, Self>;
+        G: Fn(OVector<Self::Dual<T, F>, Self>, &A) -> OVector<Self::Dual<T, F>, M>,
+        DefaultAllocator: Allocator<M> + Allocator<M, Self>;
 }
 
 impl<const N: usize> Gradients for Const<N> {
@@ -567,15 +643,22 @@ impl<const N: usize> Gradients for Const<N> {
         (a, b, c, d)
     }
 
-    fn jacobian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Self::Dual<T, F>, F>>(
+    fn jacobian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<Self::Dual<T, F>, F>,
+    >(
         g: G,
         x: &OVector<T, Self>,
         args: &A::Inner,
-    ) -> (OVector<T, Self>, OMatrix<T, Self, Self>)
+    ) -> (OVector<T, M>, OMatrix<T, M, Self>)
     where
-        G: Fn(OVector<DualVec<T, F, Self>, Self>, &A) -> OVector<DualVec<T, F, Self>, Self>,
+        G: Fn(OVector<DualVec<T, F, Self>, Self>, &A) -> OVector<DualVec<T, F, Self>, M>,
+        DefaultAllocator: Allocator<M> + Allocator<M, Self>,
     {
-        jacobian(partial(g, args), x)
+        jacobian::<_, T, F, M, Self, _>(partial(g, args), x)
     }
 }
 
@@ -662,28 +745,36 @@ impl Gradients for Dyn {
         (re, grad_x, grad_y, hessian)
     }
 
-    fn jacobian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Self::Dual<T, F>, F>>(
+    fn jacobian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<Self::Dual<T, F>, F>,
+    >(
         g: G,
         x: &OVector<T, Self>,
         args: &A::Inner,
-    ) -> (OVector<T, Self>, OMatrix<T, Self, Self>)
+    ) -> (OVector<T, M>, OMatrix<T, M, Self>)
     where
-        G: Fn(OVector<Dual<T, F>, Self>, &A) -> OVector<Dual<T, F>, Self>,
-        DefaultAllocator: Allocator<Self, Self>,
+        G: Fn(OVector<Dual<T, F>, Self>, &A) -> OVector<Dual<T, F>, M>,
+        DefaultAllocator: Allocator<M> + Allocator<M, Self>,
     {
         let n = x.len();
         let args = A::from_inner(args);
-        let mut f = DVector::zeros(n);
+        let mut f = None;
         let columns: Vec<_> = (0..n)
             .map(|i| {
                 let mut x = x.map(Dual::from_re);
                 x[i].eps = T::one();
                 let res = g(x, &args);
-                f = res.map(|r| r.re);
+                f = Some(res.map(|r| r.re));
                 res.map(|r| r.eps)
             })
             .collect();
-        let jac = DMatrix::from_columns(&columns);
+        let f = f.unwrap_or_else(|| g(x.map(Dual::from_re), &args).map(|r| r.re));
+        let (m, _) = f.shape_generic();
+        let jac = OMatrix::from_fn_generic(m, Dyn(n), |i, j| columns[j][i]);
         (f, jac)
     }
 }
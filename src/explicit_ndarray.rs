@@ -0,0 +1,301 @@
+use crate::*;
+use ndarray::{Array, Array1, Array2, ArrayView, ArrayView1, Dimension, Ix1};
+
+/// Evaluation of gradients, hessians, partial (Nx1) hessians, and (rectangular) Jacobians of functions
+/// of `ndarray` arrays.
+///
+/// Analogous to [`Gradients`] for nalgebra vectors, the trait is implemented for the dimension of the
+/// input array, which determines the dual number types that the function is evaluated with.
+/// ```
+/// # use num_dual::{DualNum, GradientsNd};
+/// # use ndarray::{arr1, Array1};
+/// fn foo<D: DualNum<f64> + Copy>(x: Array1<D>, a: &D) -> D {
+///     x.mapv(|x| x * x).sum() * a
+/// }
+///
+/// let x = arr1(&[1.0, 2.0]);
+/// let (f, grad) = GradientsNd::gradient(foo, x.view(), &2.0);
+/// assert_eq!(f, 10.0);
+/// assert_eq!(grad, arr1(&[4.0, 8.0]));
+///
+/// let (f, grad, hess) = GradientsNd::hessian(foo, x.view(), &2.0);
+/// assert_eq!(f, 10.0);
+/// assert_eq!(grad, arr1(&[4.0, 8.0]));
+/// assert_eq!(hess[(0, 0)], 4.0);
+/// assert_eq!(hess[(0, 1)], 0.0);
+/// ```
+pub trait GradientsNd: Dimension {
+    type Dual<T: DualNum<F> + Copy, F: DualNumFloat>: DualNum<F, Inner = T> + Copy;
+    type HyperDual<T: DualNum<F> + Copy, F: DualNumFloat>: DualNum<F, Inner = T> + Copy;
+
+    fn gradient<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Self::Dual<T, F>, F>>(
+        g: G,
+        x: ArrayView<T, Self>,
+        args: &A::Inner,
+    ) -> (T, Array<T, Self>)
+    where
+        G: Fn(Array<Self::Dual<T, F>, Self>, &A) -> Self::Dual<T, F>;
+
+    fn hessian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Self::HyperDual<T, F>, F>>(
+        g: G,
+        x: ArrayView<T, Self>,
+        args: &A::Inner,
+    ) -> (T, Array<T, Self>, Array<T, Self::Larger>)
+    where
+        G: Fn(Array<Self::HyperDual<T, F>, Self>, &A) -> Self::HyperDual<T, F>;
+
+    fn partial_hessian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        A: DualStruct<Self::HyperDual<T, F>, F>,
+    >(
+        g: G,
+        x: ArrayView<T, Self>,
+        y: T,
+        args: &A::Inner,
+    ) -> (T, Array<T, Self>, T, Array<T, Self>)
+    where
+        G: Fn(
+            Array<Self::HyperDual<T, F>, Self>,
+            Self::HyperDual<T, F>,
+            &A,
+        ) -> Self::HyperDual<T, F>;
+
+    /// Calculate the Jacobian of a vector function. The number of outputs is independent of the
+    /// number of variables, so rectangular Jacobians can be evaluated.
+    fn jacobian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Self::Dual<T, F>, F>>(
+        g: G,
+        x: ArrayView<T, Self>,
+        args: &A::Inner,
+    ) -> (Array1<T>, Array<T, Self::Larger>)
+    where
+        G: Fn(Array<Self::Dual<T, F>, Self>, &A) -> Array1<Self::Dual<T, F>>;
+}
+
+impl GradientsNd for Ix1 {
+    type Dual<T: DualNum<F> + Copy, F: DualNumFloat> = Dual<T, F>;
+    type HyperDual<T: DualNum<F> + Copy, F: DualNumFloat> = HyperDual<T, F>;
+
+    fn gradient<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Dual<T, F>, F>>(
+        g: G,
+        x: ArrayView1<T>,
+        args: &A::Inner,
+    ) -> (T, Array1<T>)
+    where
+        G: Fn(Array1<Dual<T, F>>, &A) -> Dual<T, F>,
+    {
+        let mut re = T::zero();
+        let args = A::from_inner(args);
+        let grad = Array1::from_shape_fn(x.len(), |i| {
+            let mut x = x.mapv(Dual::from_re);
+            x[i].eps = T::one();
+            let res = g(x, &args);
+            re = res.re;
+            res.eps
+        });
+        (re, grad)
+    }
+
+    /// Every entry on and above the diagonal is evaluated in a separate sweep using a [`HyperDual`] number.
+    fn hessian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<HyperDual<T, F>, F>>(
+        g: G,
+        x: ArrayView1<T>,
+        args: &A::Inner,
+    ) -> (T, Array1<T>, Array2<T>)
+    where
+        G: Fn(Array1<HyperDual<T, F>>, &A) -> HyperDual<T, F>,
+    {
+        let mut re = T::zero();
+        let n = x.len();
+        let args = A::from_inner(args);
+        let mut grad = Array1::zeros(n);
+        let mut hessian = Array2::zeros((n, n));
+        for i in 0..n {
+            for j in i..n {
+                let mut x = x.mapv(HyperDual::from_re);
+                x[i].eps1 = T::one();
+                x[j].eps2 = T::one();
+                let res = g(x, &args);
+                re = res.re;
+                grad[i] = res.eps1;
+                hessian[(i, j)] = res.eps1eps2;
+                hessian[(j, i)] = res.eps1eps2;
+            }
+        }
+        (re, grad, hessian)
+    }
+
+    fn partial_hessian<
+        G,
+        T: DualNum<F> + Copy,
+        F: DualNumFloat,
+        A: DualStruct<HyperDual<T, F>, F>,
+    >(
+        g: G,
+        x: ArrayView1<T>,
+        y: T,
+        args: &A::Inner,
+    ) -> (T, Array1<T>, T, Array1<T>)
+    where
+        G: Fn(Array1<HyperDual<T, F>>, HyperDual<T, F>, &A) -> HyperDual<T, F>,
+    {
+        let mut re = T::zero();
+        let args = A::from_inner(args);
+        let y = HyperDual::from_re(y).derivative2();
+        let mut grad_x = Array1::zeros(x.len());
+        let mut grad_y = T::zero();
+        let hessian = Array1::from_shape_fn(x.len(), |i| {
+            let mut x = x.mapv(HyperDual::from_re);
+            x[i].eps1 = T::one();
+            let res = g(x, y, &args);
+            re = res.re;
+            grad_x[i] = res.eps1;
+            grad_y = res.eps2;
+            res.eps1eps2
+        });
+        (re, grad_x, grad_y, hessian)
+    }
+
+    fn jacobian<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Dual<T, F>, F>>(
+        g: G,
+        x: ArrayView1<T>,
+        args: &A::Inner,
+    ) -> (Array1<T>, Array2<T>)
+    where
+        G: Fn(Array1<Dual<T, F>>, &A) -> Array1<Dual<T, F>>,
+    {
+        let n = x.len();
+        let args = A::from_inner(args);
+        let mut f = None;
+        let columns: Vec<_> = (0..n)
+            .map(|i| {
+                let mut x = x.mapv(Dual::from_re);
+                x[i].eps = T::one();
+                let res = g(x, &args);
+                f = Some(res.mapv(|r| r.re));
+                res.mapv(|r| r.eps)
+            })
+            .collect();
+        let f = f.unwrap_or_else(|| g(x.mapv(Dual::from_re), &args).mapv(|r| r.re));
+        let jac = Array2::from_shape_fn((f.len(), n), |(i, j)| columns[j][i]);
+        (f, jac)
+    }
+}
+
+/// Calculate the gradient of a scalar function of an [`Array1`].
+///
+/// Analogous to [`Gradients::gradient`] for dynamically sized inputs, the gradient is evaluated by
+/// repeated scalar sweeps, so the function can rely on the [`Copy`] trait bound. See [`GradientsNd`]
+/// for the underlying trait.
+/// ```
+/// # use num_dual::{gradient_nd, DualNum};
+/// # use ndarray::{arr1, Array1};
+/// # use approx::assert_relative_eq;
+/// fn foo<D: DualNum<f64> + Copy>(x: Array1<D>, n: &D) -> D {
+///     x.dot(&x).sqrt() - n
+/// }
+///
+/// let x = arr1(&[1.0, 5.0, 5.0, 7.0]);
+/// let (f, grad) = gradient_nd(foo, x.view(), &10.0);
+/// assert_eq!(f, 0.0);
+/// assert_relative_eq!(grad[0], 0.1);
+/// assert_relative_eq!(grad[3], 0.7);
+/// ```
+pub fn gradient_nd<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Dual<T, F>, F>>(
+    g: G,
+    x: ArrayView1<T>,
+    args: &A::Inner,
+) -> (T, Array1<T>)
+where
+    G: Fn(Array1<Dual<T, F>>, &A) -> Dual<T, F>,
+{
+    Ix1::gradient(g, x, args)
+}
+
+/// Calculate the (rectangular) Jacobian of a vector function of an [`Array1`].
+/// ```
+/// # use num_dual::{jacobian_nd, DualNum};
+/// # use ndarray::{arr1, Array1};
+/// # use approx::assert_relative_eq;
+/// fn foo<D: DualNum<f64> + Copy>(x: Array1<D>, _: &()) -> Array1<D> {
+///     Array1::from(vec![x[0] * x[1].powi(3) * x[2], x[0].powi(2) * x[1] * x[2].powi(2)])
+/// }
+///
+/// let x = arr1(&[5.0, 3.0, 2.0]);
+/// let (f, jac) = jacobian_nd(foo, x.view(), &());
+/// assert_eq!(f, arr1(&[270.0, 300.0]));
+/// assert_eq!(jac.dim(), (2, 3));
+/// assert_relative_eq!(jac[(0, 1)], 270.0);    // 3xy²z
+/// assert_relative_eq!(jac[(1, 2)], 300.0);    // 2x²yz
+/// ```
+pub fn jacobian_nd<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<Dual<T, F>, F>>(
+    g: G,
+    x: ArrayView1<T>,
+    args: &A::Inner,
+) -> (Array1<T>, Array2<T>)
+where
+    G: Fn(Array1<Dual<T, F>>, &A) -> Array1<Dual<T, F>>,
+{
+    Ix1::jacobian(g, x, args)
+}
+
+/// Calculate the Hessian of a scalar function of an [`Array1`].
+/// ```
+/// # use num_dual::{hessian_nd, DualNum};
+/// # use ndarray::{arr1, Array1};
+/// # use approx::assert_relative_eq;
+/// fn foo<D: DualNum<f64> + Copy>(x: Array1<D>, _: &()) -> D {
+///     x.dot(&x).sqrt()
+/// }
+///
+/// let (f, grad, hess) = hessian_nd(foo, arr1(&[4.0, 3.0]).view(), &());
+/// assert_eq!(f, 5.0);
+/// assert_relative_eq!(grad[0], 0.8);
+/// assert_relative_eq!(grad[1], 0.6);
+/// assert_relative_eq!(hess[(0, 0)], 0.072);
+/// assert_relative_eq!(hess[(0, 1)], -0.096);
+/// assert_relative_eq!(hess[(1, 1)], 0.128);
+/// ```
+pub fn hessian_nd<G, T: DualNum<F> + Copy, F: DualNumFloat, A: DualStruct<HyperDual<T, F>, F>>(
+    g: G,
+    x: ArrayView1<T>,
+    args: &A::Inner,
+) -> (T, Array1<T>, Array2<T>)
+where
+    G: Fn(Array1<HyperDual<T, F>>, &A) -> HyperDual<T, F>,
+{
+    Ix1::hessian(g, x, args)
+}
+
+/// Calculate second partial derivatives of a scalar function with respect to an [`Array1`] and a scalar.
+/// ```
+/// # use num_dual::{partial_hessian_nd, DualNum};
+/// # use ndarray::{arr1, Array1};
+/// # use approx::assert_relative_eq;
+/// fn foo<D: DualNum<f64> + Copy>(x: Array1<D>, y: D, _: &()) -> D {
+///     (x.dot(&x) + y * y).sqrt()
+/// }
+///
+/// let (f, dfdx, dfdy, d2fdxdy) = partial_hessian_nd(foo, arr1(&[4.0]).view(), 3.0, &());
+/// assert_eq!(f, 5.0);
+/// assert_relative_eq!(dfdx[0], 0.8);
+/// assert_relative_eq!(dfdy, 0.6);
+/// assert_relative_eq!(d2fdxdy[0], -0.096);
+/// ```
+pub fn partial_hessian_nd<
+    G,
+    T: DualNum<F> + Copy,
+    F: DualNumFloat,
+    A: DualStruct<HyperDual<T, F>, F>,
+>(
+    g: G,
+    x: ArrayView1<T>,
+    y: T,
+    args: &A::Inner,
+) -> (T, Array1<T>, T, Array1<T>)
+where
+    G: Fn(Array1<HyperDual<T, F>>, HyperDual<T, F>, &A) -> HyperDual<T, F>,
+{
+    Ix1::partial_hessian(g, x, y, args)
+}
@@ -1,10 +1,10 @@
-use crate::linalg::LU;
+use crate::linalg::{Krylov, LU, LinAlgError, Preconditioner};
 use crate::{
-    Dual, DualNum, DualNumFloat, DualSVec, DualStruct, DualVec, Gradients, first_derivative,
-    jacobian, partial,
+    Dual, DualNum, DualNumFloat, DualParts, DualStruct, DualVec, Gradients, HyperDual, Real,
+    first_derivative, jacobian, partial,
 };
 use nalgebra::allocator::Allocator;
-use nalgebra::{DefaultAllocator, Dim, OVector, SVector, U1, U2};
+use nalgebra::{DVector, DefaultAllocator, Dim, Dyn, OMatrix, OVector, SVector, Scalar, U1, U2};
 use std::marker::PhantomData;
 
 /// Calculate the derivative of the unary implicit function
@@ -100,7 +100,7 @@ where
 /// ```
 pub fn implicit_derivative_vec<
     G,
-    D: DualNum<F> + Copy,
+    D: DualNum<F>,
     F: DualNumFloat,
     A: DualStruct<DualVec<D, F, N>, F>,
     N: Dim,
@@ -172,17 +172,484 @@ where
     x
 }
 
+/// Calculate the derivative of the unary implicit function
+///         g(x, args) = 0
+/// using a single (real) derivative of the residual.
+///
+/// The result is identical to [implicit_derivative]. Instead of a full Newton step with dual
+/// derivatives in every iteration, the residual is evaluated once as `h` with a [`Dual<F, F>`](Dual)
+/// number and the real part of the parameters to obtain the real derivative at the solution. Every
+/// order of the Taylor expansion of the solution is then determined by a single evaluation of `g` with
+/// the dual numbers of the parameters (wrapped in [`Real`]). Usually, `g` and `h` are the same generic
+/// function.
+/// ```
+/// # use num_dual::{implicit_derivative_taylor, DualNum, Dual3, Dual64};
+/// # use approx::assert_relative_eq;
+/// fn g<D: DualNum<f64> + Copy>(x: D, y: &D) -> D {
+///     x.powi(2) - *y
+/// }
+/// let y = Dual3::from_re(Dual64::from(25.0).derivative()).derivative();
+/// let x = implicit_derivative_taylor(g, g, 5.0f64, &y);
+/// assert_relative_eq!(x.re.re, y.sqrt().re.re, max_relative=1e-16);
+/// assert_relative_eq!(x.v1.eps, y.sqrt().v1.eps, max_relative=1e-16);
+/// assert_relative_eq!(x.v3.re, y.sqrt().v3.re, max_relative=1e-16);
+/// ```
+pub fn implicit_derivative_taylor<
+    G,
+    H,
+    D: DualNum<F>,
+    F: DualNum<F> + DualNumFloat,
+    A: DualStruct<Real<D, F>, F>,
+    B: DualStruct<Dual<F, F>, F, Inner = A::Real>,
+>(
+    g: G,
+    h: H,
+    x: F,
+    args: &A::Inner,
+) -> D
+where
+    G: Fn(Real<D, F>, &A) -> Real<D, F>,
+    H: Fn(Dual<F, F>, &B) -> Dual<F, F>,
+{
+    let args = A::from_inner(args);
+    let args_re = B::from_inner(&args.re());
+    let (_, df) = first_derivative(|x| h(x, &args_re), x);
+    let mut x = D::from(x);
+    for _ in 0..D::NDERIV {
+        x -= g(Real::from_re(x.clone()), &args).re / df;
+    }
+    x
+}
+
+/// Calculate the derivative of the binary implicit function
+///         g(x, y, args) = 0
+/// using a single (real) Jacobian of the residual.
+///
+/// The result is identical to [implicit_derivative_binary], see [implicit_derivative_taylor] for details.
+pub fn implicit_derivative_binary_taylor<
+    G,
+    H,
+    D: DualNum<F>,
+    F: DualNum<F> + DualNumFloat,
+    A: DualStruct<Real<D, F>, F>,
+    B: DualStruct<DualVec<F, F, U2>, F, Inner = A::Real>,
+>(
+    g: G,
+    h: H,
+    x: F,
+    y: F,
+    args: &A::Inner,
+) -> [D; 2]
+where
+    G: Fn(Real<D, F>, Real<D, F>, &A) -> [Real<D, F>; 2],
+    H: Fn(DualVec<F, F, U2>, DualVec<F, F, U2>, &B) -> [DualVec<F, F, U2>; 2],
+{
+    let args = A::from_inner(args);
+    let args_re = B::from_inner(&args.re());
+    let (_, jac) = jacobian(
+        |x| {
+            let [[x, y]] = x.data.0;
+            SVector::from(h(x, y, &args_re))
+        },
+        &SVector::from([x, y]),
+    );
+    let [[j00, j10], [j01, j11]] = jac.data.0;
+    let det = j00 * j11 - j01 * j10;
+    let [[i00, i01], [i10, i11]] = [[j11 / det, -j01 / det], [-j10 / det, j00 / det]];
+    let mut x = D::from(x);
+    let mut y = D::from(y);
+    for _ in 0..D::NDERIV {
+        let [f0, f1] = g(Real::from_re(x.clone()), Real::from_re(y.clone()), &args);
+        x -= f0.re.clone() * i00 + f1.re.clone() * i01;
+        y -= f0.re * i10 + f1.re * i11;
+    }
+    [x, y]
+}
+
+/// Calculate the derivative of the multivariate implicit function
+///         g(x, args) = 0
+/// using a single (real) Jacobian of the residual.
+///
+/// The result is identical to [implicit_derivative_vec], see [implicit_derivative_taylor] for details.
+/// The real Jacobian is decomposed once, and every order of the solution is determined by a
+/// single evaluation of `g` and a linear solve using [LU::solve_dual].
+pub fn implicit_derivative_vec_taylor<
+    G,
+    H,
+    D: DualNum<F>,
+    F: DualNum<F> + DualNumFloat,
+    A: DualStruct<Real<D, F>, F>,
+    B: DualStruct<DualVec<F, F, N>, F, Inner = A::Real>,
+    N: Dim,
+>(
+    g: G,
+    h: H,
+    x: OVector<F, N>,
+    args: &A::Inner,
+) -> OVector<D, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<N, N> + Allocator<U1, N>,
+    G: Fn(OVector<Real<D, F>, N>, &A) -> OVector<Real<D, F>, N>,
+    H: Fn(OVector<DualVec<F, F, N>, N>, &B) -> OVector<DualVec<F, F, N>, N>,
+{
+    let args = A::from_inner(args);
+    let args_re = B::from_inner(&args.re());
+    let (_, jac) = jacobian(|x| h(x, &args_re), &x);
+    let lu = LU::new(jac).unwrap();
+    let mut x = x.map(D::from);
+    for _ in 0..D::NDERIV {
+        let f = g(x.map(Real::from_re), &args).map(|f| f.re);
+        x -= lu.solve_dual(&f);
+    }
+    x
+}
+
+/// Calculate the derivative of stationary points of the scalar potential
+///         g(x, args)
+/// using a single (real) Hessian.
+///
+/// The result is identical to [implicit_derivative_sp] and `g` has the same signature, so that both
+/// functions can be used interchangeably. The Hessian is only decomposed once (using its real part),
+/// and every order of the solution is determined by the gradient and a linear solve using [LU::solve_dual].
+/// Because the gradient is obtained from the second-order dual numbers, the Hessian is still evaluated in
+/// every order, so that the savings are limited to the decomposition.
+pub fn implicit_derivative_sp_taylor<
+    G,
+    D: DualNum<F> + Copy,
+    F: DualNum<F> + DualNumFloat,
+    A: DualStruct<N::Dual2<D, F>, F>,
+    N: Gradients,
+>(
+    g: G,
+    x: OVector<F, N>,
+    args: &A::Inner,
+) -> OVector<D, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<N, N> + Allocator<U1, N>,
+    G: Fn(OVector<N::Dual2<D, F>, N>, &A) -> N::Dual2<D, F>,
+{
+    let mut x = x.map(D::from);
+    let (_, mut grad, hess) = N::hessian(&g, &x, args);
+    let lu = LU::new(hess.map(|h| h.re())).unwrap();
+    for i in 0..D::NDERIV {
+        if i > 0 {
+            (_, grad, _) = N::hessian(&g, &x, args);
+        }
+        x -= lu.solve_dual(&grad);
+    }
+    x
+}
+
+/// Calculate the derivative of the multivariate implicit function
+///         g(x, args) = 0
+/// with a matrix-free iterative linear solver.
+///
+/// Instead of forming and decomposing the Jacobian, only Jacobian-vector products are evaluated.
+/// For these, the residual is evaluated as `h` with a single [`Dual<F, F>`](Dual) number per
+/// variable and the real part of the parameters. `g` is only evaluated with the dual numbers of the
+/// parameters (wrapped in [`Real`]) to obtain the right-hand sides. Usually, `g` and `h` are the same
+/// generic function. The linear systems for every order and every real component of the derivatives
+/// (including nested dual numbers) are solved with the Krylov method specified by `solver` and the
+/// optional preconditioner, which makes the function suitable for systems with a large number of
+/// variables.
+/// ```
+/// # use num_dual::{implicit_derivative_krylov, Dual2_64, DualNum};
+/// # use num_dual::linalg::Krylov;
+/// # use nalgebra::DVector;
+/// # use approx::assert_relative_eq;
+/// fn g<D: DualNum<f64> + Copy>(x: DVector<D>, a: &D) -> DVector<D> {
+///     let n = x.len();
+///     DVector::from_fn(n, |i, _| x[i].powi(3) + x[i] + x[(i + 1) % n] - x[(i + n - 1) % n] - a)
+/// }
+/// let n = 100;
+/// let a = Dual2_64::from(2.0).derivative();
+/// let solver = Krylov::Gmres { restart: 20, tolerance: 1e-12, max_iter: 100 };
+/// let x = implicit_derivative_krylov(g, g, DVector::repeat(n, 1.0), &a, solver, None).unwrap();
+/// assert_relative_eq!(x[0].re, 1.0, max_relative = 1e-14);
+/// assert_relative_eq!(x[0].v1, 0.25, max_relative = 1e-10);
+/// assert_relative_eq!(x[n - 1].v2, -0.09375, max_relative = 1e-10);
+/// ```
+pub fn implicit_derivative_krylov<
+    G,
+    H,
+    D: DualParts<F> + Copy,
+    F: DualNum<F> + DualNumFloat,
+    A: DualStruct<Real<D, F>, F>,
+    B: DualStruct<Dual<F, F>, F, Inner = A::Real>,
+>(
+    g: G,
+    h: H,
+    x: DVector<F>,
+    args: &A::Inner,
+    solver: Krylov<F>,
+    preconditioner: Option<Preconditioner<F>>,
+) -> Result<DVector<D>, LinAlgError>
+where
+    G: Fn(DVector<Real<D, F>>, &A) -> DVector<Real<D, F>>,
+    H: Fn(DVector<Dual<F, F>>, &B) -> DVector<Dual<F, F>>,
+{
+    let n = x.len();
+    let args = A::from_inner(args);
+    let args_re = B::from_inner(&args.re());
+    let jvp = |v: &DVector<F>| h(x.zip_map(v, Dual::new), &args_re).map(|r| r.eps);
+    let mut y = x.map(D::from);
+    for _ in 0..D::NDERIV {
+        let f = g(y.map(Real::from_re), &args).map(|f| f.re);
+        // Residuals that do not depend on (all) parameters have uninitialized derivatives.
+        // Adding zero with the combined structure of all residuals fills them with zeros.
+        let zero = f.iter().fold(D::zero(), |acc, &f| acc + f * F::zero());
+        let f = f.map(|f| f + zero);
+        let reals: Vec<_> = f.iter().map(|f| f.to_reals()).collect();
+        let dy = (0..reals.first().map_or(0, Vec::len))
+            .map(|k| {
+                let b = DVector::from_fn(n, |i, _| reals[i][k]);
+                solver.solve(jvp, &b, preconditioner)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        y -= DVector::from_fn(n, |i, _| f[i].with_reals(&mut dy.iter().map(|dy| dy[i])));
+    }
+    Ok(y)
+}
+
+/// Calculate the derivative of the solution of the equality-constrained optimization problem
+///         min f(x, args)  s.t.  c(x, args) = 0
+///
+/// The primal variables `x` and the multipliers `lambda` have to be determined beforehand, e.g., by an NLP
+/// solver like IPOPT. The multipliers are defined via the Lagrangian L = f + λᵀc, which is consistent with
+/// the constraint multipliers returned by IPOPT. The KKT conditions ∇ₓL = 0 and c = 0 are differentiated
+/// implicitly to obtain the dual-valued primal variables and multipliers.
+/// ```
+/// # use num_dual::{implicit_derivative_kkt, Dual64, DualNum, HyperDual};
+/// # use approx::assert_relative_eq;
+/// # use nalgebra::dvector;
+/// let p = Dual64::from(2.0).derivative();
+/// let (x, lambda) = implicit_derivative_kkt(
+///     |x, p: &HyperDual<_, _>| (x[0] - p).powi(2) + x[1].powi(2),
+///     |x, _| dvector![x[0] + x[1] - 1.0],
+///     dvector![1.5, -0.5],
+///     dvector![1.0],
+///     &p,
+/// );
+/// assert_relative_eq!(x[0].re, 1.5, max_relative = 1e-15);
+/// assert_relative_eq!(x[0].eps, 0.5, max_relative = 1e-15);
+/// assert_relative_eq!(x[1].eps, -0.5, max_relative = 1e-15);
+/// assert_relative_eq!(lambda[0].re, 1.0, max_relative = 1e-15);
+/// assert_relative_eq!(lambda[0].eps, 1.0, max_relative = 1e-15);
+/// ```
+pub fn implicit_derivative_kkt<
+    G,
+    C,
+    D: DualNum<F> + Copy,
+    F: DualNumFloat,
+    A: DualStruct<HyperDual<D, F>, F>,
+>(
+    f: G,
+    c: C,
+    x: DVector<F>,
+    lambda: DVector<F>,
+    args: &A::Inner,
+) -> (DVector<D>, DVector<D>)
+where
+    G: Fn(DVector<HyperDual<D, F>>, &A) -> HyperDual<D, F>,
+    C: Fn(DVector<HyperDual<D, F>>, &A) -> DVector<HyperDual<D, F>>,
+{
+    let (n, m) = (x.len(), lambda.len());
+    // The KKT conditions are the stationarity conditions of the Lagrangian with respect to x and λ.
+    let lagrangian = |z: DVector<HyperDual<D, F>>, args: &A| {
+        let x = z.rows(0, n).into_owned();
+        let c = c(x.clone(), args);
+        assert_eq!(
+            c.len(),
+            m,
+            "the number of constraints and multipliers has to match"
+        );
+        c.iter()
+            .zip(z.rows(n, m).iter())
+            .fold(f(x, args), |l, (&c, &lambda)| l + c * lambda)
+    };
+    let z = DVector::from_iterator(n + m, x.iter().chain(lambda.iter()).copied());
+    let z = implicit_derivative_sp::<_, _, _, _, Dyn>(lagrangian, z, args);
+    (z.rows(0, n).into_owned(), z.rows(n, m).into_owned())
+}
+
+/// Calculate the derivative of the solution of the inequality-constrained optimization problem
+///         min f(x, args)  s.t.  c(x, args) ≤ 0
+/// with a known active set.
+///
+/// Constraints for which `active` is `true` are treated as equality constraints in the KKT system (see
+/// [implicit_derivative_kkt]), equality constraints of the original problem are, therefore, always active.
+/// The multipliers of inactive constraints are zero, and the inactive constraints are not evaluated in the
+/// KKT system. The active set has to remain unchanged in a neighborhood of the solution, i.e., strict
+/// complementarity is assumed.
+/// ```
+/// # use num_dual::{implicit_derivative_kkt_active, Dual64, DualNum, HyperDual};
+/// # use approx::assert_relative_eq;
+/// # use nalgebra::dvector;
+/// let p = Dual64::from(2.0).derivative();
+/// let (x, lambda) = implicit_derivative_kkt_active(
+///     |x, p: &HyperDual<_, _>| (x[0] - p).powi(2) + (x[1] - p).powi(2),
+///     |x, _| dvector![x[0] + x[1] - 1.0, x[0] - x[1] - 5.0],
+///     &[true, false],
+///     dvector![0.5, 0.5],
+///     dvector![3.0, 0.0],
+///     &p,
+/// );
+/// assert_relative_eq!(x[0].re, 0.5, max_relative = 1e-15);
+/// assert_relative_eq!(x[0].eps, 0.0, max_relative = 1e-15);
+/// assert_relative_eq!(lambda[0].re, 3.0, max_relative = 1e-15);
+/// assert_relative_eq!(lambda[0].eps, 2.0, max_relative = 1e-15);
+/// assert_eq!(lambda[1], Dual64::from(0.0));
+/// ```
+pub fn implicit_derivative_kkt_active<
+    G,
+    C,
+    D: DualNum<F> + Copy,
+    F: DualNumFloat,
+    A: DualStruct<HyperDual<D, F>, F>,
+>(
+    f: G,
+    c: C,
+    active: &[bool],
+    x: DVector<F>,
+    lambda: DVector<F>,
+    args: &A::Inner,
+) -> (DVector<D>, DVector<D>)
+where
+    G: Fn(DVector<HyperDual<D, F>>, &A) -> HyperDual<D, F>,
+    C: Fn(DVector<HyperDual<D, F>>, &A) -> DVector<HyperDual<D, F>>,
+{
+    assert_eq!(
+        active.len(),
+        lambda.len(),
+        "the number of constraints and multipliers has to match"
+    );
+    let (x, lambda_active) = implicit_derivative_kkt(
+        f,
+        |x, args| select_active(&c(x, args), active),
+        x,
+        select_active(&lambda, active),
+        args,
+    );
+    let mut lambda_active = lambda_active.into_iter();
+    let lambda = DVector::from_iterator(
+        active.len(),
+        active.iter().map(|&a| {
+            if a {
+                *lambda_active.next().unwrap()
+            } else {
+                D::zero()
+            }
+        }),
+    );
+    (x, lambda)
+}
+
+fn select_active<T: Copy + Scalar>(v: &DVector<T>, active: &[bool]) -> DVector<T> {
+    let v = v.iter().zip(active).filter(|&(_, &a)| a).map(|(&v, _)| v);
+    DVector::from_iterator(active.iter().filter(|&&a| a).count(), v)
+}
+
+/// Calculate the derivative of the fixed point
+///         x = t(x, args)
+/// of a scalar function.
+///
+/// The fixed point is differentiated implicitly using the residual x - t(x, args), i.e., the derivatives
+/// are obtained from (1 - ∂t/∂x)⁻¹ without the need to reformulate the map.
+/// ```
+/// # use num_dual::{implicit_fixed_point, Dual, DualNum, Dual2_64};
+/// # use approx::assert_relative_eq;
+/// // Babylonian method for the square root
+/// let y = Dual2_64::from(25.0).derivative();
+/// let x = implicit_fixed_point(|x, y: &Dual<_, _>| (x + y / x) * 0.5, 5.0f64, &y);
+/// assert_relative_eq!(x.re, y.sqrt().re, max_relative=1e-16);
+/// assert_relative_eq!(x.v1, y.sqrt().v1, max_relative=1e-16);
+/// assert_relative_eq!(x.v2, y.sqrt().v2, max_relative=1e-16);
+/// ```
+pub fn implicit_fixed_point<G, D: DualNum<F>, F: DualNumFloat, A: DualStruct<Dual<D, F>, F>>(
+    t: G,
+    x: F,
+    args: &A::Inner,
+) -> D
+where
+    G: Fn(Dual<D, F>, &A) -> Dual<D, F>,
+{
+    let mut x = D::from(x);
+    for _ in 0..D::NDERIV {
+        let (tx, dtx) = first_derivative(partial(&t, args), x.clone());
+        x -= (x.clone() - tx) / (-dtx + F::one());
+    }
+    x
+}
+
+/// Calculate the derivative of the fixed point
+///         x = t(x, args)
+/// of a multivariate function.
+///
+/// The derivatives are obtained from (I - ∂t/∂x)⁻¹ using the Jacobian of the map, which is
+/// evaluated with [`DualVec`] numbers for statically and dynamically sized variables.
+/// ```
+/// # use num_dual::{implicit_fixed_point_vec, Dual64, DualNum, DualSVec, DualVec};
+/// # use approx::assert_relative_eq;
+/// # use nalgebra::{vector, dvector, DVector, Dyn, SVector};
+/// let a = Dual64::from(4.0).derivative();
+/// let t = |x: SVector<DualSVec<Dual64, f64, 2>, 2>, a: &DualSVec<_, _, 2>| {
+///     vector![(x[1] / a).sqrt(), x[0] * a]
+/// };
+/// let x = implicit_fixed_point_vec(t, vector![1.0f64, 4.0f64], &a);
+/// assert_relative_eq!(x[0].re, 1.0, max_relative = 1e-16);
+/// assert_relative_eq!(x[0].eps, 0.0, max_relative = 1e-16);
+/// assert_relative_eq!(x[1].re, a.re, max_relative = 1e-16);
+/// assert_relative_eq!(x[1].eps, a.eps, max_relative = 1e-16);
+///
+/// let t = |x: DVector<DualVec<Dual64, f64, Dyn>>, a: &DualVec<_, _, _>| {
+///     dvector![(x[1].clone() / a).sqrt(), x[0].clone() * a]
+/// };
+/// let x = implicit_fixed_point_vec(t, dvector![1.0f64, 4.0f64], &a);
+/// assert_relative_eq!(x[0].re, 1.0, max_relative = 1e-16);
+/// assert_relative_eq!(x[0].eps, 0.0, max_relative = 1e-16);
+/// assert_relative_eq!(x[1].re, a.re, max_relative = 1e-16);
+/// assert_relative_eq!(x[1].eps, a.eps, max_relative = 1e-16);
+/// ```
+pub fn implicit_fixed_point_vec<
+    G,
+    D: DualNum<F>,
+    F: DualNumFloat,
+    A: DualStruct<DualVec<D, F, N>, F>,
+    N: Dim,
+>(
+    t: G,
+    x: OVector<F, N>,
+    args: &A::Inner,
+) -> OVector<D, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<N, N> + Allocator<U1, N>,
+    G: Fn(OVector<DualVec<D, F, N>, N>, &A) -> OVector<DualVec<D, F, N>, N>,
+{
+    let mut x = x.map(D::from);
+    let (n, _) = x.shape_generic();
+    let args = A::from_inner(args);
+    for _ in 0..D::NDERIV {
+        let (tx, jac) = jacobian(|x| t(x, &args), &x);
+        let residual = &x - tx;
+        x -= LU::new(OMatrix::identity_generic(n, n) - jac)
+            .unwrap()
+            .solve(&residual);
+    }
+    x
+}
+
 /// An implicit function g(x, args) = 0 for which derivatives of x can be
 /// calculated with the [ImplicitDerivative] struct.
 pub trait ImplicitFunction<F> {
     /// data type of the parameter struct, needs to implement [DualStruct].
     type Parameters<D>;
 
-    /// data type of the variable `x`, needs to be either `D`, `[D; 2]`, or `SVector<D, N>`.
+    /// data type of the variable `x`, needs to be either `D`, `[D; 2]`, `SVector<D, N>`, or
+    /// `DVector<D>` (see [ImplicitVariable]).
     type Variable<D>;
 
     /// implementation of the residual function g(x, args) = 0.
-    fn residual<D: DualNum<F> + Copy>(
+    fn residual<D: DualNum<F>>(
         x: Self::Variable<D>,
         parameters: &Self::Parameters<D>,
     ) -> Self::Variable<D>;
@@ -191,14 +658,14 @@ pub trait ImplicitFunction<F> {
 /// Helper struct that stores parameters in dual and real form and provides functions
 /// for evaluating real residuals (for external solvers) and implicit derivatives for
 /// arbitrary dual numbers.
-pub struct ImplicitDerivative<G: ImplicitFunction<F>, D: DualNum<F> + Copy, F: DualNumFloat, V> {
+pub struct ImplicitDerivative<G: ImplicitFunction<F>, D: DualNum<F>, F: DualNumFloat, V> {
     base: G::Parameters<D::Real>,
     derivative: G::Parameters<D>,
     phantom: PhantomData<V>,
 }
 
-impl<G: ImplicitFunction<F>, D: DualNum<F> + Copy, F: DualNum<F> + DualNumFloat>
-    ImplicitDerivative<G, D, F, G::Variable<f64>>
+impl<G: ImplicitFunction<F>, D: DualNum<F>, F: DualNum<F> + DualNumFloat>
+    ImplicitDerivative<G, D, F, G::Variable<F>>
 where
     G::Parameters<D>: DualStruct<D, F, Real = G::Parameters<F>>,
 {
@@ -216,24 +683,105 @@ where
     }
 }
 
-impl<G: ImplicitFunction<F>, D: DualNum<F> + Copy, F: DualNum<F> + DualNumFloat>
-    ImplicitDerivative<G, D, F, F>
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The variable of an [ImplicitFunction], which determines how implicit derivatives are
+/// evaluated in [ImplicitDerivative].
+///
+/// The trait is sealed and implemented for scalars (`f32`, `f64`) and vectors (`OVector<F, N>`)
+/// of statically or dynamically sized dimension. Bivariate functions (`[F; 2]`) are handled by a
+/// separate [ImplicitDerivative::implicit_derivative] that accepts both variables as arguments.
+pub trait ImplicitVariable<F: DualNumFloat>: sealed::Sealed + Sized {
+    /// dual number used to evaluate the Jacobian of the residual.
+    type Dual<D: DualNum<F>>: DualNum<F, Inner = D>;
+
+    /// data type of the variable with elements of type `T`.
+    type Variable<T: Scalar>;
+
+    /// Evaluate the implicit derivative of the residual `g` at the real solution `x`.
+    fn implicit_derivative<G, D: DualNum<F>, A: DualStruct<Self::Dual<D>, F>>(
+        g: G,
+        x: Self,
+        args: &A::Inner,
+    ) -> Self::Variable<D>
+    where
+        G: Fn(Self::Variable<Self::Dual<D>>, &A) -> Self::Variable<Self::Dual<D>>;
+}
+
+macro_rules! impl_implicit_variable_float {
+    ($float:ty) => {
+        impl sealed::Sealed for $float {}
+
+        impl ImplicitVariable<$float> for $float {
+            type Dual<D: DualNum<$float>> = Dual<D, $float>;
+            type Variable<T: Scalar> = T;
+
+            fn implicit_derivative<G, D: DualNum<$float>, A: DualStruct<Dual<D, $float>, $float>>(
+                g: G,
+                x: Self,
+                args: &A::Inner,
+            ) -> D
+            where
+                G: Fn(Dual<D, $float>, &A) -> Dual<D, $float>,
+            {
+                implicit_derivative(g, x, args)
+            }
+        }
+    };
+}
+
+impl_implicit_variable_float!(f32);
+impl_implicit_variable_float!(f64);
+
+impl<F: DualNumFloat, N: Dim> sealed::Sealed for OVector<F, N> where DefaultAllocator: Allocator<N> {}
+
+impl<F: DualNumFloat, N: Dim> ImplicitVariable<F> for OVector<F, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<N, N> + Allocator<U1, N>,
+{
+    type Dual<D: DualNum<F>> = DualVec<D, F, N>;
+    type Variable<T: Scalar> = OVector<T, N>;
+
+    fn implicit_derivative<G, D: DualNum<F>, A: DualStruct<DualVec<D, F, N>, F>>(
+        g: G,
+        x: Self,
+        args: &A::Inner,
+    ) -> OVector<D, N>
+    where
+        G: Fn(OVector<DualVec<D, F, N>, N>, &A) -> OVector<DualVec<D, F, N>, N>,
+    {
+        implicit_derivative_vec(g, x, args)
+    }
+}
+
+impl<G: ImplicitFunction<F>, D: DualNum<F>, F: DualNum<F> + DualNumFloat, V: ImplicitVariable<F>>
+    ImplicitDerivative<G, D, F, V>
 where
     G::Parameters<D>: DualStruct<D, F, Real = G::Parameters<F>>,
 {
-    /// Evaluate the implicit derivative for a scalar function.
-    pub fn implicit_derivative<A: DualStruct<Dual<D, F>, F, Inner = G::Parameters<D>>>(
+    /// Evaluate the implicit derivative.
+    ///
+    /// The parameters can be any dual number, e.g., a [HyperDualVec](crate::HyperDualVec), a
+    /// [Dual2Vec](crate::Dual2Vec), or a [DualDVec](crate::DualDVec) to calculate (partial)
+    /// derivatives of the solution with respect to the parameters.
+    pub fn implicit_derivative<A: DualStruct<V::Dual<D>, F, Inner = G::Parameters<D>>>(
         &self,
-        x: F,
-    ) -> D
+        x: V,
+    ) -> V::Variable<D>
     where
-        G: ImplicitFunction<F, Variable<Dual<D, F>> = Dual<D, F>, Parameters<Dual<D, F>> = A>,
+        G: ImplicitFunction<
+                F,
+                Variable<V::Dual<D>> = V::Variable<V::Dual<D>>,
+                Parameters<V::Dual<D>> = A,
+            >,
     {
-        implicit_derivative(G::residual::<Dual<D, F>>, x, &self.derivative)
+        V::implicit_derivative(G::residual::<V::Dual<D>>, x, &self.derivative)
     }
 }
 
-impl<G: ImplicitFunction<F>, D: DualNum<F> + Copy, F: DualNum<F> + DualNumFloat>
+impl<G: ImplicitFunction<F>, D: DualNum<F>, F: DualNum<F> + DualNumFloat>
     ImplicitDerivative<G, D, F, [F; 2]>
 where
     G::Parameters<D>: DualStruct<D, F, Real = G::Parameters<F>>,
@@ -260,30 +808,94 @@ where
     }
 }
 
-impl<G: ImplicitFunction<F>, D: DualNum<F> + Copy, F: DualNum<F> + DualNumFloat, const N: usize>
-    ImplicitDerivative<G, D, F, SVector<F, N>>
+/// A fixed point x = t(x, args) for which derivatives of x can be
+/// calculated with the [ImplicitFixedPoint] struct.
+pub trait FixedPointFunction<F> {
+    /// data type of the parameter struct, needs to implement [DualStruct].
+    type Parameters<D>;
+
+    /// data type of the variable `x`, needs to be either `D`, `SVector<D, N>`, or `DVector<D>`.
+    type Variable<D>;
+
+    /// implementation of the map x = t(x, args).
+    fn map<D: DualNum<F>>(
+        x: Self::Variable<D>,
+        parameters: &Self::Parameters<D>,
+    ) -> Self::Variable<D>;
+}
+
+/// Helper struct that stores parameters in dual and real form and provides functions
+/// for evaluating the real map (for external fixed-point iterations) and implicit derivatives
+/// of the fixed point for arbitrary dual numbers.
+pub struct ImplicitFixedPoint<G: FixedPointFunction<F>, D: DualNum<F>, F: DualNumFloat, V> {
+    base: G::Parameters<D::Real>,
+    derivative: G::Parameters<D>,
+    phantom: PhantomData<V>,
+}
+
+impl<G: FixedPointFunction<F>, D: DualNum<F>, F: DualNum<F> + DualNumFloat>
+    ImplicitFixedPoint<G, D, F, G::Variable<F>>
+where
+    G::Parameters<D>: DualStruct<D, F, Real = G::Parameters<F>>,
+{
+    pub fn new(_: G, parameters: G::Parameters<D>) -> Self {
+        Self {
+            base: parameters.re(),
+            derivative: parameters,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Evaluate the (real) map.
+    pub fn map(&self, x: G::Variable<F>) -> G::Variable<F> {
+        G::map(x, &self.base)
+    }
+}
+
+impl<G: FixedPointFunction<F>, D: DualNum<F>, F: DualNum<F> + DualNumFloat>
+    ImplicitFixedPoint<G, D, F, F>
 where
     G::Parameters<D>: DualStruct<D, F, Real = G::Parameters<F>>,
 {
-    /// Evaluate the implicit derivative for a multivariate function.
-    pub fn implicit_derivative<A: DualStruct<DualSVec<D, F, N>, F, Inner = G::Parameters<D>>>(
+    /// Evaluate the implicit derivative for a scalar fixed point.
+    pub fn implicit_derivative<A: DualStruct<Dual<D, F>, F, Inner = G::Parameters<D>>>(
         &self,
-        x: SVector<F, N>,
-    ) -> SVector<D, N>
+        x: F,
+    ) -> D
     where
-        G: ImplicitFunction<
+        G: FixedPointFunction<F, Variable<Dual<D, F>> = Dual<D, F>, Parameters<Dual<D, F>> = A>,
+    {
+        implicit_fixed_point(G::map::<Dual<D, F>>, x, &self.derivative)
+    }
+}
+
+impl<G: FixedPointFunction<F>, D: DualNum<F>, F: DualNum<F> + DualNumFloat, N: Dim>
+    ImplicitFixedPoint<G, D, F, OVector<F, N>>
+where
+    G::Parameters<D>: DualStruct<D, F, Real = G::Parameters<F>>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N> + Allocator<U1, N>,
+{
+    /// Evaluate the implicit derivative for a statically or dynamically sized fixed point.
+    pub fn implicit_derivative<A: DualStruct<DualVec<D, F, N>, F, Inner = G::Parameters<D>>>(
+        &self,
+        x: OVector<F, N>,
+    ) -> OVector<D, N>
+    where
+        G: FixedPointFunction<
                 F,
-                Variable<DualSVec<D, F, N>> = SVector<DualSVec<D, F, N>, N>,
-                Parameters<DualSVec<D, F, N>> = A,
+                Variable<DualVec<D, F, N>> = OVector<DualVec<D, F, N>, N>,
+                Parameters<DualVec<D, F, N>> = A,
             >,
     {
-        implicit_derivative_vec(G::residual::<DualSVec<D, F, N>>, x, &self.derivative)
+        implicit_fixed_point_vec(G::map::<DualVec<D, F, N>>, x, &self.derivative)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{Derivative, Dual2Vec, Dual64};
+    use approx::assert_relative_eq;
     use nalgebra::SVector;
 
     struct TestFunction;
@@ -291,8 +903,8 @@ mod test {
         type Parameters<D> = D;
         type Variable<D> = D;
 
-        fn residual<D: DualNum<f64> + Copy>(x: D, square: &D) -> D {
-            *square - x * x
+        fn residual<D: DualNum<f64>>(x: D, square: &D) -> D {
+            square.clone() - x.clone() * x
         }
     }
 
@@ -301,8 +913,11 @@ mod test {
         type Parameters<D> = (D, D);
         type Variable<D> = [D; 2];
 
-        fn residual<D: DualNum<f64> + Copy>([x, y]: [D; 2], (square_sum, sum): &(D, D)) -> [D; 2] {
-            [*square_sum - x * x - y * y, *sum - x - y]
+        fn residual<D: DualNum<f64>>([x, y]: [D; 2], (square_sum, sum): &(D, D)) -> [D; 2] {
+            [
+                square_sum.clone() - x.clone() * &x - y.clone() * &y,
+                sum.clone() - x - y,
+            ]
         }
     }
 
@@ -311,16 +926,372 @@ mod test {
         type Parameters<D> = D;
         type Variable<D> = SVector<D, N>;
 
-        fn residual<D: DualNum<f64> + Copy>(x: SVector<D, N>, &square_sum: &D) -> SVector<D, N> {
-            let mut res = x;
+        fn residual<D: DualNum<f64>>(x: SVector<D, N>, square_sum: &D) -> SVector<D, N> {
+            let mut res = x.clone();
             for i in 1..N {
-                res[i] = x[i] - x[i - 1] - D::from(1.0);
+                res[i] = x[i].clone() - &x[i - 1] - D::from(1.0);
+            }
+            res[0] = square_sum.clone() - x.dot(&x);
+            res
+        }
+    }
+
+    struct Babylonian;
+    impl FixedPointFunction<f64> for Babylonian {
+        type Parameters<D> = D;
+        type Variable<D> = D;
+
+        fn map<D: DualNum<f64>>(x: D, square: &D) -> D {
+            (x.clone() + square.clone() / x) * 0.5
+        }
+    }
+
+    struct Averages<const N: usize>;
+    impl<const N: usize> FixedPointFunction<f64> for Averages<N> {
+        type Parameters<D> = D;
+        type Variable<D> = SVector<D, N>;
+
+        fn map<D: DualNum<f64>>(x: SVector<D, N>, a: &D) -> SVector<D, N> {
+            SVector::from_fn(|i, _| (x[(i + 1) % N].clone() + a) * 0.5)
+        }
+    }
+
+    struct DynAverages;
+    impl FixedPointFunction<f64> for DynAverages {
+        type Parameters<D> = D;
+        type Variable<D> = DVector<D>;
+
+        fn map<D: DualNum<f64>>(x: DVector<D>, a: &D) -> DVector<D> {
+            let n = x.len();
+            DVector::from_fn(n, |i, _| (x[(i + 1) % n].clone() + a) * 0.5)
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let f: crate::Dual64 = Dual::from(25.0).derivative();
+        let func = ImplicitFixedPoint::new(Babylonian, f);
+        assert_eq!(func.map(5.0), 5.0);
+        assert_eq!(f.sqrt(), func.implicit_derivative(5.0));
+
+        let a: crate::Dual64 = Dual::from(3.0).derivative();
+        let func = ImplicitFixedPoint::new(Averages, a);
+        assert_eq!(func.map(SVector::from([3.0; 3])), SVector::from([3.0; 3]));
+        let x = func.implicit_derivative(SVector::from([3.0; 3]));
+        for xi in x.iter() {
+            assert_eq!(*xi, a);
+        }
+
+        let func = ImplicitFixedPoint::new(DynAverages, a);
+        assert_eq!(func.map(DVector::repeat(4, 3.0)), DVector::repeat(4, 3.0));
+        let x = func.implicit_derivative(DVector::repeat(4, 3.0));
+        assert_eq!(x.len(), 4);
+        for xi in x.iter() {
+            assert_eq!(*xi, a);
+        }
+
+        // dynamically sized dual numbers are not Copy
+        let f = crate::DualDVec64::new(25.0, Derivative::some(DVector::from_vec(vec![1.0, 2.0])));
+        let func = ImplicitFixedPoint::new(Babylonian, f.clone());
+        assert_eq!(f.sqrt(), func.implicit_derivative(5.0));
+
+        let a = crate::DualDVec64::new(3.0, Derivative::some(DVector::from_vec(vec![1.0, 2.0])));
+        let func = ImplicitFixedPoint::new(DynAverages, a.clone());
+        let x = func.implicit_derivative(DVector::repeat(4, 3.0));
+        for xi in x.iter() {
+            assert_eq!(xi.re, a.re);
+            assert_relative_eq!(
+                xi.eps.clone().unwrap_generic(Dyn(2), U1),
+                a.eps.clone().unwrap_generic(Dyn(2), U1)
+            );
+        }
+    }
+
+    type Dual3Dual64 = crate::Dual3<crate::Dual64, f64>;
+
+    fn assert_dual3_eq(a: Dual3Dual64, b: Dual3Dual64) {
+        for (a, b) in [(a.re, b.re), (a.v1, b.v1), (a.v2, b.v2), (a.v3, b.v3)] {
+            assert_relative_eq!(a.re, b.re, max_relative = 1e-12, epsilon = 1e-12);
+            assert_relative_eq!(a.eps, b.eps, max_relative = 1e-12, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_taylor() {
+        let a = Dual3Dual64::from_re(crate::Dual64::from(4.0).derivative()).derivative();
+
+        fn scalar<D: DualNum<f64> + Copy>(x: D, a: &D) -> D {
+            x.powi(3) - *a * x - 1.0
+        }
+        let x0 = implicit_derivative(scalar, 2.1149075414767564, &a);
+        let x1 = implicit_derivative_taylor(scalar, scalar, 2.1149075414767564, &a);
+        assert_dual3_eq(x0, x1);
+
+        fn binary<D: DualNum<f64> + Copy>(x: D, y: D, a: &D) -> [D; 2] {
+            [x * y - *a, x + y - *a - 1.0]
+        }
+        let [x0, y0] = implicit_derivative_binary(binary, 1.0, 4.0, &a);
+        let [x1, y1] = implicit_derivative_binary_taylor(binary, binary, 1.0, 4.0, &a);
+        assert_dual3_eq(x0, x1);
+        assert_dual3_eq(y0, y1);
+        assert_dual3_eq(y1, a);
+
+        fn vector<D: DualNum<f64> + Copy>(x: SVector<D, 2>, a: &D) -> SVector<D, 2> {
+            SVector::from([x[0] * x[1] - *a, x[0].powi(2) + x[1] - a.sqrt() - 3.0])
+        }
+        let x0 = implicit_derivative_vec(vector, SVector::from([1.0, 4.0]), &a);
+        let x1 = implicit_derivative_vec_taylor(vector, vector, SVector::from([1.0, 4.0]), &a);
+        assert_dual3_eq(x0[0], x1[0]);
+        assert_dual3_eq(x0[1], x1[1]);
+
+        let g = |x: DVector<HyperDual<Dual3Dual64, f64>>, a: &HyperDual<_, _>| {
+            (a - x[0]).powi(2) + (x[1] - x[0] * a).powi(2) + (x[0] * x[1] - a.powi(3)).powi(4)
+        };
+        let x0 = implicit_derivative_sp(g, DVector::from_vec(vec![4.0, 16.0]), &a);
+        let x1 = implicit_derivative_sp_taylor(g, DVector::from_vec(vec![4.0, 16.0]), &a);
+        assert_dual3_eq(x0[0], x1[0]);
+        assert_dual3_eq(x0[1], x1[1]);
+        assert_dual3_eq(x1[1], a * a);
+
+        fn potential<D: DualNum<f64> + Copy>(x: SVector<D, 2>, a: &D) -> D {
+            (*a - x[0]).powi(2) + (x[1] - x[0] * *a).powi(2) + (x[0] * x[1] - a.powi(3)).powi(4)
+        }
+        let x0 = implicit_derivative_sp(potential, SVector::from([4.0, 16.0]), &a);
+        let x1 = implicit_derivative_sp_taylor(potential, SVector::from([4.0, 16.0]), &a);
+        assert_dual3_eq(x0[0], x1[0]);
+        assert_dual3_eq(x0[1], x1[1]);
+
+        let g = |x: SVector<Dual2Vec<Dual3Dual64, f64, U2>, 2>, a: &Dual2Vec<_, _, _>| {
+            (a - x[0]).powi(2) + (x[1] - x[0] * a).powi(2) + (x[0] * x[1] - a.powi(3)).powi(4)
+        };
+        let x0 = implicit_derivative_sp(g, SVector::from([4.0, 16.0]), &a);
+        let x1 = implicit_derivative_sp_taylor(g, SVector::from([4.0, 16.0]), &a);
+        assert_dual3_eq(x0[0], x1[0]);
+        assert_dual3_eq(x0[1], x1[1]);
+    }
+
+    fn residual<D: DualNum<f64> + Copy>(x: DVector<D>, a: &D) -> DVector<D> {
+        DVector::from_vec(vec![
+            x[0] * x[1] - a,
+            x[0] + x[1] + x[2].powi(2) - a - 1.0,
+            x[2].exp() - x[0],
+        ])
+    }
+
+    #[test]
+    fn test_krylov() {
+        let g = |x: SVector<DualVec<_, _, nalgebra::U3>, 3>, a: &DualVec<_, _, nalgebra::U3>| {
+            SVector::from([
+                x[0] * x[1] - a,
+                x[0] + x[1] + x[2].powi(2) - a - 1.0,
+                x[2].exp() - x[0],
+            ])
+        };
+        let a = crate::HyperDual64::new(4.0, 1.0, 2.0, 0.5);
+        let x0 = implicit_derivative_vec(g, SVector::from([1.0, 4.0, 0.0]), &a);
+        let jacobi = |r: &DVector<f64>| r.component_div(&DVector::from_vec(vec![4.0, 1.0, 1.0]));
+        for (solver, preconditioner) in [
+            (
+                Krylov::Gmres {
+                    restart: 2,
+                    tolerance: 1e-14,
+                    max_iter: 100,
+                },
+                None,
+            ),
+            (
+                Krylov::BiCgStab {
+                    tolerance: 1e-14,
+                    max_iter: 100,
+                },
+                Some(&jacobi as Preconditioner<f64>),
+            ),
+        ] {
+            let x1 = implicit_derivative_krylov(
+                residual,
+                residual,
+                DVector::from_vec(vec![1.0, 4.0, 0.0]),
+                &a,
+                solver,
+                preconditioner,
+            )
+            .unwrap();
+            // the same derivatives with nested dual numbers
+            let a_nested = Dual::new(Dual64::new(4.0, 1.0), Dual64::new(2.0, 0.5));
+            let x2 = implicit_derivative_krylov(
+                residual,
+                residual,
+                DVector::from_vec(vec![1.0, 4.0, 0.0]),
+                &a_nested,
+                solver,
+                preconditioner,
+            )
+            .unwrap();
+            for ((x0, x1), x2) in x0.iter().zip(x1.iter()).zip(x2.iter()) {
+                assert_relative_eq!(x0.re, x1.re, epsilon = 1e-12);
+                assert_relative_eq!(x0.eps1, x1.eps1, epsilon = 1e-12);
+                assert_relative_eq!(x0.eps2, x1.eps2, epsilon = 1e-12);
+                assert_relative_eq!(x0.eps1eps2, x1.eps1eps2, epsilon = 1e-12);
+                assert_relative_eq!(x0.re, x2.re.re, epsilon = 1e-12);
+                assert_relative_eq!(x0.eps1, x2.re.eps, epsilon = 1e-12);
+                assert_relative_eq!(x0.eps2, x2.eps.re, epsilon = 1e-12);
+                assert_relative_eq!(x0.eps1eps2, x2.eps.eps, epsilon = 1e-12);
             }
-            res[0] = square_sum - x.dot(&x);
+        }
+    }
+
+    #[test]
+    fn test_krylov_independent_residual() {
+        // the last residual does not depend on the parameters
+        fn g<D: DualNum<f64> + Copy>(x: SVector<D, 3>, a: &D) -> SVector<D, 3> {
+            SVector::from([
+                x[0] * x[1] - a,
+                x[0] + x[1] + x[2].powi(2) - a - 1.0,
+                x[2].exp() - x[0],
+            ])
+        }
+        let solver = Krylov::Gmres {
+            restart: 3,
+            tolerance: 1e-14,
+            max_iter: 100,
+        };
+        let x = DVector::from_vec(vec![1.0, 4.0, 0.0]);
+
+        let a = crate::DualSVec64::<2>::from_re(4.0).derivative(0);
+        let x0 = implicit_derivative_vec(g, SVector::from([1.0, 4.0, 0.0]), &a);
+        let x1 =
+            implicit_derivative_krylov(residual, residual, x.clone(), &a, solver, None).unwrap();
+        for (x0, x1) in x0.iter().zip(x1.iter()) {
+            assert_relative_eq!(x0.re, x1.re, epsilon = 1e-12);
+            assert_relative_eq!(
+                x0.eps.unwrap_generic(nalgebra::U2, U1),
+                x1.eps.unwrap_generic(nalgebra::U2, U1),
+                epsilon = 1e-12
+            );
+        }
+
+        let a = crate::HyperDualSVec64::<1, 2>::from_re(4.0)
+            .derivative1(0)
+            .derivative2(1);
+        let x0 = implicit_derivative_vec(g, SVector::from([1.0, 4.0, 0.0]), &a);
+        let x1 = implicit_derivative_krylov(residual, residual, x, &a, solver, None).unwrap();
+        for (x0, x1) in x0.iter().zip(x1.iter()) {
+            assert_relative_eq!(x0.re, x1.re, epsilon = 1e-12);
+            assert_relative_eq!(
+                x0.eps1eps2.unwrap_generic(U1, nalgebra::U2),
+                x1.eps1eps2.unwrap_generic(U1, nalgebra::U2),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_kkt() {
+        // min x0² + x1² + x2²  s.t.  x0 + x1 + x2 = p, x0 - x1 ≤ -1, x2 ≤ 10
+        let p = crate::Dual2_64::from(4.0).derivative();
+        let (x, lambda) = implicit_derivative_kkt_active(
+            |x, _: &HyperDual<_, _>| x.dot(&x),
+            |x, p| DVector::from_vec(vec![x[0] + x[1] + x[2] - p, x[0] - x[1] + 1.0, x[2] - 10.0]),
+            &[true, true, false],
+            DVector::from_vec(vec![5.0 / 6.0, 11.0 / 6.0, 4.0 / 3.0]),
+            DVector::from_vec(vec![-8.0 / 3.0, 1.0, 0.0]),
+            &p,
+        );
+        let x0 = p / 3.0 - 0.5;
+        let l0 = -p * 2.0 / 3.0;
+        assert_relative_eq!(x[0].re, x0.re, max_relative = 1e-14);
+        assert_relative_eq!(x[0].v1, x0.v1, max_relative = 1e-14);
+        assert_relative_eq!(x[0].v2, x0.v2, epsilon = 1e-14);
+        assert_relative_eq!(x[2].v1, 1.0 / 3.0, max_relative = 1e-14);
+        assert_relative_eq!(lambda[0].re, l0.re, max_relative = 1e-14);
+        assert_relative_eq!(lambda[0].v1, l0.v1, max_relative = 1e-14);
+        assert_relative_eq!(lambda[1].re, 1.0, max_relative = 1e-14);
+        assert_relative_eq!(lambda[1].v1, 0.0, epsilon = 1e-14);
+        assert_eq!(lambda[2], crate::Dual2_64::from(0.0));
+    }
+
+    struct TestFunction4;
+    impl ImplicitFunction<f64> for TestFunction4 {
+        type Parameters<D> = D;
+        type Variable<D> = DVector<D>;
+
+        fn residual<D: DualNum<f64>>(x: DVector<D>, square_sum: &D) -> DVector<D> {
+            let mut res = x.clone();
+            for i in 1..x.len() {
+                res[i] = x[i].clone() - &x[i - 1] - D::from(1.0);
+            }
+            res[0] = square_sum.clone() - x.dot(&x);
             res
         }
     }
 
+    #[test]
+    fn test_second_derivatives() {
+        let a = crate::HyperDualSVec64::<1, 1>::from_re(25.0).derivative1(0);
+        let b = crate::HyperDualSVec64::<1, 1>::from_re(7.0).derivative2(0);
+        let func = ImplicitDerivative::new(TestFunction2, (a, b));
+        let [x, y] = func.implicit_derivative(4.0, 3.0);
+        let xa = (b + (a * 2.0 - b * b).sqrt()) * 0.5;
+        let ya = (b - (a * 2.0 - b * b).sqrt()) * 0.5;
+        assert_relative_eq!(
+            x.eps1eps2.unwrap(),
+            xa.eps1eps2.unwrap(),
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            y.eps1eps2.unwrap(),
+            ya.eps1eps2.unwrap(),
+            max_relative = 1e-14
+        );
+
+        let a = crate::Dual2SVec64::<2>::new(
+            25.0,
+            Derivative::some(nalgebra::RowSVector::from([1.0, 0.0])),
+            Derivative::none(),
+        );
+        let b = crate::Dual2SVec64::<2>::new(
+            7.0,
+            Derivative::some(nalgebra::RowSVector::from([0.0, 1.0])),
+            Derivative::none(),
+        );
+        let func = ImplicitDerivative::new(TestFunction2, (a, b));
+        let [x, _] = func.implicit_derivative(4.0, 3.0);
+        let xa = (b + (a * 2.0 - b * b).sqrt()) * 0.5;
+        assert_relative_eq!(
+            x.v2.unwrap_generic(U2, U2),
+            xa.v2.unwrap_generic(U2, U2),
+            max_relative = 1e-14
+        );
+
+        let s = crate::Dual2_64::from(30.0).derivative();
+        let func = ImplicitDerivative::new(TestFunction4, s);
+        let x = func.implicit_derivative(DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]));
+        let x0 = ((s - 5.0).sqrt() - 5.0) * 0.5;
+        for (i, x) in x.iter().enumerate() {
+            assert_relative_eq!(x.re, x0.re + i as f64 + 1.0, max_relative = 1e-14);
+            assert_relative_eq!(x.v1, x0.v1, max_relative = 1e-14);
+            assert_relative_eq!(x.v2, x0.v2, max_relative = 1e-14);
+        }
+
+        let a = crate::DualDVec64::from_re(25.0).derivative(2, 0);
+        let b = crate::DualDVec64::from_re(7.0).derivative(2, 1);
+        let eps = |x: &crate::DualDVec64| x.eps.0.clone().unwrap();
+        let s = crate::DualDVec64::from_re(30.0).derivative(2, 0);
+        let func = ImplicitDerivative::new(TestFunction3::<4>, s.clone());
+        let x = func.implicit_derivative(SVector::from([1.0, 2.0, 3.0, 4.0]));
+        let x0 = ((s - 5.0).sqrt() - 5.0) * 0.5;
+        for x in x.iter() {
+            assert_relative_eq!(eps(x), eps(&x0), max_relative = 1e-14);
+        }
+        let func = ImplicitDerivative::new(TestFunction2, (a.clone(), b.clone()));
+        let [x, y] = func.implicit_derivative(4.0, 3.0);
+        let root = (a * 2.0 - b.clone() * &b).sqrt();
+        let xa = (b.clone() + &root) * 0.5;
+        let ya = (b - root) * 0.5;
+        assert_relative_eq!(eps(&x), eps(&xa), max_relative = 1e-14);
+        assert_relative_eq!(eps(&y), eps(&ya), max_relative = 1e-14);
+    }
+
     #[test]
     fn test() {
         let f: crate::Dual64 = Dual::from(25.0).derivative();
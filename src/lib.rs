@@ -141,7 +141,14 @@
 //! by iteratively evaluating scalar derivatives. For functions that do not rely on the [`Copy`] trait bound,
 //! only benchmarking can reveal Whether the increased performance through the avoidance of heap allocations
 //! can overcome the overhead of repeated function evaluations, i.e., if [`Gradients`] outperforms directly
-//! calling [`gradient`], [`hessian`], [`partial_hessian`] or [`jacobian`].
+//! calling [`gradient`], [`hessian`], [`partial_hessian`] or [`jacobian`]. As a third option, [`Chunked`]
+//! processes the variables in blocks of a compile-time size using statically sized dual numbers.
+//!
+//! ## Uncertainty propagation and sensitivity analysis
+//! The [`uncertainty`] module uses [`DualVec`] and [`Dual2Vec`] to propagate the mean and covariance of uncertain
+//! inputs ([`uncertainty::Uncertain`]) through any function that is generic over the dual number type, either
+//! linearized or with a second-order correction. The [`sensitivity`] module evaluates absolute, relative, and
+//! log-log sensitivities of models with named parameters and ranks the parameters by their importance.
 //!
 //! # Derivatives of implicit functions
 //! Implicit differentiation is used to determine the derivative `dy/dx` where the output `y` is only related
@@ -175,7 +182,18 @@
 //! The `implicit_sqrt` or any likewise defined function is generic over the dual type `D`
 //! and can, therefore, be used anywhere as a part of an arbitrary complex computation. The functions
 //! [`implicit_derivative_binary`] and [`implicit_derivative_vec`] can be used for implicit functions
-//! with more than one variable.
+//! with more than one variable. Problems that are naturally formulated as fixed points `x = t(x, args)`, like
+//! self-consistent or successive substitution iterations, can be differentiated with [`implicit_fixed_point`] and
+//! [`implicit_fixed_point_vec`] without rewriting the map as a residual.
+//!
+//! For higher-order or nested dual numbers, the variants [`implicit_derivative_taylor`],
+//! [`implicit_derivative_binary_taylor`], [`implicit_derivative_vec_taylor`], and [`implicit_derivative_sp_taylor`]
+//! provide identical results, but only decompose the real Jacobian once and determine every order of the solution
+//! with a single linear solve, instead of repeatedly decomposing the Jacobian including all derivatives. Except for
+//! [`implicit_derivative_sp_taylor`], they take the residual twice (usually the same generic function), once evaluated
+//! with first-order dual numbers for the real Jacobian and once with the dual numbers of the parameters.
+//! For large systems, [`implicit_derivative_krylov`] avoids the Jacobian altogether and solves the linear systems
+//! with matrix-free iterative solvers ([`linalg::gmres`], [`linalg::bicgstab`]) using only Jacobian-vector products.
 //!
 //! For implicit functions that contain complex models and a large number of parameters, the [`ImplicitDerivative`]
 //! interface might come in handy. The idea is to define the implicit function using the [`ImplicitFunction`] trait
@@ -189,8 +207,8 @@
 //! impl ImplicitFunction<f64> for ImplicitSqrt {
 //!     type Parameters<D> = D;
 //!     type Variable<D> = D;
-//!     fn residual<D: DualNum<f64> + Copy>(x: D, square: &D) -> D {
-//!         *square - x * x
+//!     fn residual<D: DualNum<f64>>(x: D, square: &D) -> D {
+//!         square.clone() - x.clone() * x
 //!     }
 //! }
 //!
@@ -201,6 +219,8 @@
 //!     assert_eq!(x.sqrt(), func.implicit_derivative(5.0));
 //! }
 //! ```
+//! Analogously, fixed points can be defined using the [`FixedPointFunction`] trait and the [`ImplicitFixedPoint`]
+//! struct.
 //!
 //! ## Combination with nonlinear solver libraries
 //! As mentioned previously, this crate does not contain any algorithms for nonlinear optimization or root finding.
@@ -213,6 +233,10 @@
 //! it only requires a function that returns the values of the optimization variable and constraints), without
 //! any repercussions regarding the robustness or speed of convergence of the solver.
 //!
+//! The solutions of constrained optimization problems can be differentiated with [`implicit_derivative_kkt`] (for
+//! equality constraints) and [`implicit_derivative_kkt_active`] (for inequality constraints with a known active set)
+//! using the primal variables and multipliers returned by the solver.
+//!
 //! If you are developing nonlinear optimization algorithms in Rust, feel free to reach out to us. We are happy to
 //! discuss how to enhance your algorithms with the automatic differentiation capabilities of this crate.
 
@@ -237,10 +261,15 @@ mod nalgebra_macros;
 mod impl_derivatives;
 
 mod bessel;
+mod chunked;
 mod datatypes;
 mod explicit;
+#[cfg(feature = "ndarray")]
+mod explicit_ndarray;
 mod implicit;
+mod parts;
 pub use bessel::BesselDual;
+pub use chunked::{Chunked, DEFAULT_CHUNK_SIZE};
 pub use datatypes::derivative::Derivative;
 pub use datatypes::dual::{Dual, Dual32, Dual64};
 pub use datatypes::dual_vec::{
@@ -260,16 +289,33 @@ pub use datatypes::hyperdual_vec::{
 pub use datatypes::hyperhyperdual::{HyperHyperDual, HyperHyperDual32, HyperHyperDual64};
 pub use datatypes::real::Real;
 pub use explicit::{
-    Gradients, first_derivative, gradient, hessian, jacobian, partial, partial_hessian, partial2,
-    partial3, second_derivative, second_partial_derivative, third_derivative,
-    third_partial_derivative, third_partial_derivative_vec, zeroth_derivative,
+    FirstDerivative, Gradient, Gradients, first_derivative, gradient, hessian, jacobian, partial,
+    partial_hessian, partial2, partial3, second_derivative, second_partial_derivative,
+    third_derivative, third_partial_derivative, third_partial_derivative_vec, zeroth_derivative,
 };
+#[cfg(feature = "ndarray")]
+pub use explicit_ndarray::{GradientsNd, gradient_nd, hessian_nd, jacobian_nd, partial_hessian_nd};
 pub use implicit::{
-    ImplicitDerivative, ImplicitFunction, implicit_derivative, implicit_derivative_binary,
-    implicit_derivative_sp, implicit_derivative_vec,
+    FixedPointFunction, ImplicitDerivative, ImplicitFixedPoint, ImplicitFunction, ImplicitVariable,
+    implicit_derivative, implicit_derivative_binary, implicit_derivative_binary_taylor,
+    implicit_derivative_kkt, implicit_derivative_kkt_active, implicit_derivative_krylov,
+    implicit_derivative_sp, implicit_derivative_sp_taylor, implicit_derivative_taylor,
+    implicit_derivative_vec, implicit_derivative_vec_taylor, implicit_fixed_point,
+    implicit_fixed_point_vec,
 };
+#[cfg(feature = "derive")]
+pub use num_dual_derive::{DualStruct, Mappable};
 
 pub mod linalg;
+pub mod sensitivity;
+pub mod uncertainty;
+pub use parts::{DualParts, from_parts, split_parts};
+#[cfg(feature = "ndarray")]
+pub use parts::{from_parts_nd, split_parts_nd};
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelGradients;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -685,7 +731,9 @@ impl_dual_num_float!(f64);
 /// A struct that contains dual numbers. Needed for arbitrary arguments in [ImplicitFunction].
 ///
 /// The trait is implemented for all dual types themselves, and common data types (tuple, vec,
-/// array, ...) and can be implemented for custom data types to achieve full flexibility.
+/// array, ...) and can be implemented for custom data types to achieve full flexibility. With the
+/// `derive` feature, the implementation for structs and enums that are generic over the dual number
+/// type can be generated using `#[derive(DualStruct)]`.
 pub trait DualStruct<D, F> {
     type Real;
     type Inner;
@@ -696,7 +744,9 @@ pub trait DualStruct<D, F> {
 /// Trait for structs used as an output of functions for which derivatives are calculated.
 ///
 /// The main intention is to generalize the calculation of derivatives to fallible functions, but
-/// other use cases might also appear in the future.
+/// other use cases might also appear in the future. With the `derive` feature, the implementation
+/// for structs and enums that are generic over the dual number type can be generated using
+/// `#[derive(Mappable)]`.
 pub trait Mappable<D> {
     type Output<O>;
     fn map_dual<M: Fn(D) -> O, O>(self, f: M) -> Self::Output<O>;
@@ -985,3 +1035,47 @@ where
         f(self)
     }
 }
+
+#[cfg(feature = "ndarray")]
+impl<D, F, T: DualStruct<D, F>, Ix: ndarray::Dimension> DualStruct<D, F> for ndarray::Array<T, Ix> {
+    type Real = ndarray::Array<T::Real, Ix>;
+    type Inner = ndarray::Array<T::Inner, Ix>;
+    fn re(&self) -> Self::Real {
+        self.map(|x| x.re())
+    }
+    fn from_inner(inner: &Self::Inner) -> Self {
+        inner.map(|x| T::from_inner(x))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<D, Ix: ndarray::Dimension> Mappable<Self> for ndarray::Array<D, Ix> {
+    type Output<O> = O;
+    fn map_dual<M: Fn(Self) -> O, O>(self, f: M) -> O {
+        f(self)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: DualNum<F>, F: DualNumFloat, Ix: ndarray::Dimension> FirstDerivative<T, F>
+    for ndarray::Array<Dual<T, F>, Ix>
+{
+    type Output = (ndarray::Array<T, Ix>, ndarray::Array<T, Ix>);
+    fn value_and_derivative(self) -> Self::Output {
+        (self.map(|x| x.re.clone()), self.map(|x| x.eps.clone()))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: DualNum<F>, F: DualNumFloat, D: Dim, Ix: ndarray::Dimension> Gradient<T, F, D>
+    for ndarray::Array<DualVec<T, F, D>, Ix>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    type Output = ndarray::Array<(T, nalgebra::OVector<T, D>), Ix>;
+    fn value_and_gradient(self, n: D) -> Self::Output {
+        let dim = self.raw_dim();
+        let elements = self.into_iter().map(|x| x.value_and_gradient(n)).collect();
+        ndarray::Array::from_shape_vec(dim, elements).unwrap()
+    }
+}
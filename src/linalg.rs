@@ -1,24 +1,70 @@
-//! Basic linear algebra functionalities (linear solve and eigenvalues) for matrices containing dual numbers.
+//! Basic linear algebra functionalities (linear solve and eigenvalues) for matrices containing dual numbers,
+//! and matrix-free iterative solvers for real linear systems.
 use crate::DualNum;
 use nalgebra::allocator::Allocator;
-use nalgebra::{DefaultAllocator, Dim, OMatrix, OVector, U1};
+use nalgebra::{DMatrix, DVector, DefaultAllocator, Dim, OMatrix, OVector, U1};
 use num_traits::Float;
 use std::fmt;
 use std::iter::Product;
-use std::marker::PhantomData;
 
 /// Error type for fallible linear algebra operations.
-#[derive(Debug)]
-pub struct LinAlgError();
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LinAlgError {
+    /// The LU decomposition encountered a pivot whose magnitude does not exceed the
+    /// singularity tolerance.
+    Singular {
+        /// Column in which the decomposition failed.
+        column: usize,
+        /// Magnitude of the real part of the offending pivot.
+        pivot: f64,
+        /// Estimate of the reciprocal condition number, calculated as the ratio of the
+        /// smallest and the largest pivot encountered up to the failure.
+        rcond: f64,
+    },
+    /// An iterative solver did not reach the requested tolerance.
+    NotConverged {
+        /// Number of iterations performed.
+        iterations: usize,
+        /// Norm of the final residual relative to the norm of the right-hand side.
+        residual: f64,
+    },
+}
 
 impl fmt::Display for LinAlgError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "The matrix appears to be singular.")
+        match self {
+            Self::Singular {
+                column,
+                pivot,
+                rcond,
+            } => write!(
+                f,
+                "The matrix appears to be singular (column {column}, |pivot| = {pivot:e}, rcond = {rcond:e})."
+            ),
+            Self::NotConverged {
+                iterations,
+                residual,
+            } => write!(
+                f,
+                "The iterative solver did not converge ({iterations} iterations, relative residual = {residual:e})."
+            ),
+        }
     }
 }
 
 impl std::error::Error for LinAlgError {}
 
+/// Pivoting strategy used in the [`LU`] decomposition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pivoting {
+    /// Row interchanges only.
+    #[default]
+    Partial,
+    /// Row and column interchanges. More robust for badly scaled systems, at the cost of
+    /// searching the entire remaining submatrix for each pivot.
+    Full,
+}
+
 /// LU decomposition for symmetric matrices with dual numbers as elements.
 pub struct LU<T: DualNum<F>, F, D: Dim>
 where
@@ -26,34 +72,80 @@ where
 {
     a: OMatrix<T, D, D>,
     p: OVector<usize, D>,
+    q: OVector<usize, D>,
     p_count: usize,
-    f: PhantomData<F>,
+    rcond: F,
 }
 
-impl<T: DualNum<F> + Copy, F: Float, D: Dim> LU<T, F, D>
+impl<T: DualNum<F>, F: Float, D: Dim> LU<T, F, D>
 where
     DefaultAllocator: Allocator<D, D> + Allocator<D>,
 {
-    pub fn new(mut a: OMatrix<T, D, D>) -> Result<Self, LinAlgError> {
+    /// LU decomposition with partial pivoting that fails only for exactly vanishing pivots.
+    pub fn new(a: OMatrix<T, D, D>) -> Result<Self, LinAlgError> {
+        Self::with_options(a, F::zero(), Pivoting::Partial)
+    }
+
+    /// LU decomposition with a custom singularity tolerance and pivoting strategy.
+    ///
+    /// The decomposition fails if the magnitude of the real part of a pivot is smaller than or
+    /// equal to `tolerance`.
+    /// ```
+    /// # use num_dual::linalg::{LU, LinAlgError, Pivoting};
+    /// # use nalgebra::dmatrix;
+    /// let a = dmatrix![1.0, 2.0; 2.0, 4.0 + 1e-14];
+    /// assert!(LU::new(a.clone()).is_ok());
+    /// let Err(LinAlgError::Singular { column, .. }) = LU::with_options(a, 1e-10, Pivoting::Full) else {
+    ///     panic!()
+    /// };
+    /// assert_eq!(column, 1);
+    /// ```
+    pub fn with_options(
+        mut a: OMatrix<T, D, D>,
+        tolerance: F,
+        pivoting: Pivoting,
+    ) -> Result<Self, LinAlgError> {
         let (n, _) = a.shape_generic();
         let mut p = OVector::from_iterator_generic(n, U1, 0..n.value());
+        let mut q = p.clone();
         let n = n.value();
         let mut p_count = 0;
+        let mut min_pivot = F::infinity();
+        let mut max_pivot = F::zero();
 
         for i in 0..n {
             let mut max_a = F::zero();
             let mut imax = i;
-
-            for k in i..n {
-                let abs_a = a[(k, i)].abs();
-                if abs_a.re() > max_a {
-                    max_a = abs_a.re();
-                    imax = k;
+            let mut jmax = i;
+
+            let columns = match pivoting {
+                Pivoting::Partial => i..i + 1,
+                Pivoting::Full => i..n,
+            };
+            for j in columns {
+                for k in i..n {
+                    let abs_a = a[(k, j)].abs();
+                    if abs_a.re() > max_a {
+                        max_a = abs_a.re();
+                        imax = k;
+                        jmax = j;
+                    }
                 }
             }
 
-            if max_a.is_zero() {
-                return Err(LinAlgError());
+            min_pivot = min_pivot.min(max_a);
+            max_pivot = max_pivot.max(max_a);
+            if max_a <= tolerance {
+                let rcond = if max_pivot.is_zero() {
+                    F::zero()
+                } else {
+                    min_pivot / max_pivot
+                };
+                return Err(LinAlgError::Singular {
+                    column: i,
+                    pivot: max_a.to_f64().unwrap_or(f64::NAN),
+                    rcond: rcond.to_f64().unwrap_or(f64::NAN),
+                });
             }
 
             if imax != i {
@@ -63,41 +155,62 @@ where
                 p_count += 1;
             }
 
+            if jmax != i {
+                q.swap_rows(i, jmax);
+                a.swap_columns(i, jmax);
+
+                p_count += 1;
+            }
+
             for j in i + 1..n {
-                a[(j, i)] = a[(j, i)] / a[(i, i)];
+                a[(j, i)] = a[(j, i)].clone() / &a[(i, i)];
 
                 for k in i + 1..n {
-                    a[(j, k)] = a[(j, k)] - a[(j, i)] * a[(i, k)];
+                    a[(j, k)] = a[(j, k)].clone() - a[(j, i)].clone() * &a[(i, k)];
                 }
             }
         }
+        let rcond = if n == 0 {
+            F::one()
+        } else {
+            min_pivot / max_pivot
+        };
         Ok(LU {
             a,
             p,
+            q,
             p_count,
-            f: PhantomData,
+            rcond,
         })
     }
 
+    /// Estimate of the reciprocal condition number, calculated as the ratio of the smallest
+    /// and the largest pivot.
+    pub fn rcond(&self) -> F {
+        self.rcond
+    }
+
     pub fn solve(&self, b: &OVector<T, D>) -> OVector<T, D> {
         let (n, _) = b.shape_generic();
         let mut x = OVector::zeros_generic(n, U1);
+        let mut z = OVector::zeros_generic(n, U1);
         let n = n.value();
 
         for i in 0..n {
-            x[i] = b[self.p[i]];
+            z[i] = b[self.p[i]].clone();
 
             for k in 0..i {
-                x[i] = x[i] - self.a[(i, k)] * x[k];
+                z[i] = z[i].clone() - self.a[(i, k)].clone() * &z[k];
             }
         }
 
         for i in (0..n).rev() {
             for k in i + 1..n {
-                x[i] = x[i] - self.a[(i, k)] * x[k];
+                z[i] = z[i].clone() - self.a[(i, k)].clone() * &z[k];
             }
 
-            x[i] /= self.a[(i, i)];
+            z[i] /= self.a[(i, i)].clone();
+            x[self.q[i]] = z[i].clone();
         }
 
         x
@@ -108,7 +221,7 @@ where
         T: Product,
     {
         let n = self.p.len();
-        let det = (0..n).map(|i| self.a[(i, i)]).product();
+        let det = (0..n).map(|i| self.a[(i, i)].clone()).product();
 
         if self.p_count.is_multiple_of(2) {
             det
@@ -127,53 +240,104 @@ where
                 ia[(i, j)] = if self.p[i] == j { T::one() } else { T::zero() };
 
                 for k in 0..i {
-                    ia[(i, j)] = ia[(i, j)] - self.a[(i, k)] * ia[(k, j)];
+                    ia[(i, j)] = ia[(i, j)].clone() - self.a[(i, k)].clone() * &ia[(k, j)];
                 }
             }
 
             for i in (0..n).rev() {
                 for k in i + 1..n {
-                    ia[(i, j)] = ia[(i, j)] - self.a[(i, k)] * ia[(k, j)];
+                    ia[(i, j)] = ia[(i, j)].clone() - self.a[(i, k)].clone() * &ia[(k, j)];
                 }
-                ia[(i, j)] /= self.a[(i, i)];
+                ia[(i, j)] /= self.a[(i, i)].clone();
             }
         }
 
-        ia
+        let mut inv = OMatrix::zeros_generic(r, c);
+        for i in 0..n {
+            inv.set_row(self.q[i], &ia.row(i));
+        }
+        inv
+    }
+}
+
+impl<F: DualNum<F> + Float, D: Dim> LU<F, F, D>
+where
+    DefaultAllocator: Allocator<D, D> + Allocator<D>,
+{
+    /// Solve the linear system for a right-hand side that contains dual numbers using the
+    /// decomposition of a real matrix.
+    ///
+    /// The derivatives of the solution are only correct if the matrix itself does not depend
+    /// on any of the variables with respect to which derivatives are calculated.
+    /// ```
+    /// # use num_dual::linalg::LU;
+    /// # use num_dual::{Dual64, DualNum};
+    /// # use nalgebra::{dmatrix, dvector};
+    /// let lu = LU::new(dmatrix![2.0, 1.0; 1.0, 3.0]).unwrap();
+    /// let x = lu.solve_dual(&dvector![Dual64::new(3.0, 1.0), Dual64::new(4.0, 0.0)]);
+    /// assert_eq!(x[0], Dual64::new(1.0, 0.6));
+    /// assert_eq!(x[1], Dual64::new(1.0, -0.2));
+    /// ```
+    pub fn solve_dual<T: DualNum<F>>(&self, b: &OVector<T, D>) -> OVector<T, D> {
+        let (n, _) = b.shape_generic();
+        let mut x = OVector::zeros_generic(n, U1);
+        let mut z: OVector<T, D> = OVector::zeros_generic(n, U1);
+        let n = n.value();
+
+        for i in 0..n {
+            z[i] = b[self.p[i]].clone();
+
+            for k in 0..i {
+                z[i] = z[i].clone() - z[k].clone() * self.a[(i, k)];
+            }
+        }
+
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                z[i] = z[i].clone() - z[k].clone() * self.a[(i, k)];
+            }
+
+            z[i] /= self.a[(i, i)];
+            x[self.q[i]] = z[i].clone();
+        }
+
+        x
     }
 }
 
 /// Smallest eigenvalue and corresponding eigenvector calculated using the full Jacobi
 /// eigenvalue algorithm ([`jacobi_eigenvalue`]).
-pub fn smallest_ev<T: DualNum<F> + Copy, F: Float, D: Dim>(
-    a: OMatrix<T, D, D>,
-) -> (T, OVector<T, D>)
+pub fn smallest_ev<T: DualNum<F>, F: Float, D: Dim>(a: OMatrix<T, D, D>) -> (T, OVector<T, D>)
 where
     DefaultAllocator: Allocator<D, D> + Allocator<D>,
 {
     let (r, _) = a.shape_generic();
     let n = r.value();
     if n == 1 {
-        (a[(0, 0)], OVector::from_element_generic(r, U1, T::one()))
+        (
+            a[(0, 0)].clone(),
+            OVector::from_element_generic(r, U1, T::one()),
+        )
     } else if n == 2 {
-        let (a, b, c) = (a[(0, 0)], a[(0, 1)], a[(1, 1)]);
-        let l = (a + c - ((a - c).powi(2) + b * b * F::from(4.0).unwrap()).sqrt())
+        let (a, b, c) = (&a[(0, 0)], &a[(0, 1)], &a[(1, 1)]);
+        let l = (a.clone() + c
+            - ((a.clone() - c).powi(2) + b.clone() * b * F::from(4.0).unwrap()).sqrt())
             * F::from(0.5).unwrap();
-        let theta = (b + b).atan2(a - c) * F::from(0.5).unwrap();
+        let theta = (b.clone() + b).atan2(a.clone() - c) * F::from(0.5).unwrap();
         let (s, c) = theta.sin_cos();
-        let mut u = OVector::from_fn_generic(r, U1, |i, _| [-s, c][i]);
+        let mut u = OVector::from_iterator_generic(r, U1, [-s, c]);
         if u[0].re() < F::zero() || u[0].re().is_zero() && u[1].re() < F::zero() {
             u = -u;
         }
         (l, u)
     } else {
         let (e, vecs) = jacobi_eigenvalue(a, 200);
-        (e[0], vecs.column(0).into_owned())
+        (e[0].clone(), vecs.column(0).into_owned())
     }
 }
 
 /// Eigenvalues and corresponding eigenvectors of a symmetric matrix.
-pub fn jacobi_eigenvalue<T: DualNum<F> + Copy, F: Float, D: Dim>(
+pub fn jacobi_eigenvalue<T: DualNum<F>, F: Float, D: Dim>(
     mut a: OMatrix<T, D, D>,
     max_iter: usize,
 ) -> (OVector<T, D>, OMatrix<T, D, D>)
@@ -205,64 +369,60 @@ where
         for p in 0..n {
             for q in p + 1..n {
                 let gapq = a[(p, q)].abs() * F::from(10.0).unwrap();
-                let termp = gapq + d[p].abs();
-                let termq = gapq + d[q].abs();
+                let termp = gapq.clone() + d[p].abs();
+                let termq = gapq.clone() + d[q].abs();
 
                 if 4 < it_num && termp == d[p].abs() && termq == d[q].abs() {
                     a[(p, q)] = T::zero();
                 } else if thresh <= a[(p, q)].re().abs() {
-                    let h = d[q] - d[p];
+                    let h = d[q].clone() - &d[p];
                     let term = h.abs() + gapq;
 
                     let t = if term == h.abs() {
-                        a[(p, q)] / h
+                        a[(p, q)].clone() / h
                     } else {
-                        let theta = h * F::from(0.5).unwrap() / a[(p, q)];
-                        let mut t = (theta.abs() + (theta * theta + F::one()).sqrt()).recip();
+                        let theta = h * F::from(0.5).unwrap() / &a[(p, q)];
+                        let mut t =
+                            (theta.abs() + (theta.clone() * &theta + F::one()).sqrt()).recip();
                         if theta.is_negative() {
                             t = -t;
                         }
                         t
                     };
 
-                    let c = (t * t + F::one()).sqrt().recip();
-                    let s = t * c;
-                    let tau = s / (c + F::one());
-                    let h = t * a[(p, q)];
+                    let c = (t.clone() * &t + F::one()).sqrt().recip();
+                    let s = t.clone() * &c;
+                    let tau = s.clone() / (c + F::one());
+                    let h = t * &a[(p, q)];
 
-                    zw[p] -= h;
-                    zw[q] += h;
-                    d[p] -= h;
+                    zw[p] -= h.clone();
+                    zw[q] += h.clone();
+                    d[p] -= h.clone();
                     d[q] += h;
 
                     a[(p, q)] = T::zero();
 
+                    let rotate = |g: &T, h: &T| {
+                        (
+                            g.clone() - s.clone() * (h.clone() + g.clone() * &tau),
+                            h.clone() + s.clone() * (g.clone() - h.clone() * &tau),
+                        )
+                    };
+
                     for j in 0..p {
-                        let g = a[(j, p)];
-                        let h = a[(j, q)];
-                        a[(j, p)] = g - s * (h + g * tau);
-                        a[(j, q)] = h + s * (g - h * tau);
+                        (a[(j, p)], a[(j, q)]) = rotate(&a[(j, p)], &a[(j, q)]);
                     }
 
                     for j in p + 1..q {
-                        let g = a[(p, j)];
-                        let h = a[(j, q)];
-                        a[(p, j)] = g - s * (h + g * tau);
-                        a[(j, q)] = h + s * (g - h * tau);
+                        (a[(p, j)], a[(j, q)]) = rotate(&a[(p, j)], &a[(j, q)]);
                     }
 
                     for j in q + 1..n {
-                        let g = a[(p, j)];
-                        let h = a[(q, j)];
-                        a[(p, j)] = g - s * (h + g * tau);
-                        a[(q, j)] = h + s * (g - h * tau);
+                        (a[(p, j)], a[(q, j)]) = rotate(&a[(p, j)], &a[(q, j)]);
                     }
 
                     for j in 0..n {
-                        let g = v[(j, p)];
-                        let h = v[(j, q)];
-                        v[(j, p)] = g - s * (h + g * tau);
-                        v[(j, q)] = h + s * (g - h * tau);
+                        (v[(j, p)], v[(j, q)]) = rotate(&v[(j, p)], &v[(j, q)]);
                     }
                 }
             }
@@ -294,12 +454,242 @@ where
     (d, v)
 }
 
+/// A (right) preconditioner for the iterative solvers, i.e., a function that approximates the
+/// application of the inverse of the system matrix to a vector.
+pub type Preconditioner<'a, F> = &'a dyn Fn(&DVector<F>) -> DVector<F>;
+
+/// Krylov subspace method and settings used to solve linear systems iteratively.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Krylov<F> {
+    /// Restarted GMRES ([`gmres`]).
+    Gmres {
+        /// Dimension of the Krylov subspace before a restart.
+        restart: usize,
+        /// Tolerance for the residual norm relative to the norm of the right-hand side.
+        tolerance: F,
+        /// Maximum total number of iterations.
+        max_iter: usize,
+    },
+    /// BiCGSTAB ([`bicgstab`]).
+    BiCgStab {
+        /// Tolerance for the residual norm relative to the norm of the right-hand side.
+        tolerance: F,
+        /// Maximum number of iterations.
+        max_iter: usize,
+    },
+}
+
+impl<F: DualNum<F> + Float> Krylov<F> {
+    /// Solve the linear system `a(x) = b` with the selected method.
+    pub fn solve(
+        &self,
+        a: impl Fn(&DVector<F>) -> DVector<F>,
+        b: &DVector<F>,
+        preconditioner: Option<Preconditioner<F>>,
+    ) -> Result<DVector<F>, LinAlgError> {
+        match *self {
+            Self::Gmres {
+                restart,
+                tolerance,
+                max_iter,
+            } => gmres(a, b, preconditioner, restart, tolerance, max_iter),
+            Self::BiCgStab {
+                tolerance,
+                max_iter,
+            } => bicgstab(a, b, preconditioner, tolerance, max_iter),
+        }
+    }
+}
+
+fn dot<F: Float>(a: &DVector<F>, b: &DVector<F>) -> F {
+    a.iter()
+        .zip(b.iter())
+        .fold(F::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+fn norm<F: Float>(a: &DVector<F>) -> F {
+    dot(a, a).sqrt()
+}
+
+fn not_converged<F: Float>(iterations: usize, residual: F) -> LinAlgError {
+    LinAlgError::NotConverged {
+        iterations,
+        residual: residual.to_f64().unwrap_or(f64::NAN),
+    }
+}
+
+/// Solve the linear system `a(x) = b` using the restarted generalized minimal residual method.
+///
+/// The matrix is only accessed through matrix-vector products `a(x)`. The optional preconditioner
+/// is applied from the right. The iteration stops if the norm of the residual is smaller than
+/// `tolerance` times the norm of `b`.
+/// ```
+/// # use num_dual::linalg::gmres;
+/// # use nalgebra::{dmatrix, dvector};
+/// # use approx::assert_relative_eq;
+/// let a = dmatrix![4.0, 1.0, 0.0; 1.0, 3.0, 1.0; 0.0, 1.0, 2.0];
+/// let b = dvector![1.0, 2.0, 3.0];
+/// let x = gmres(|x| &a * x, &b, None, 10, 1e-12, 100).unwrap();
+/// assert_relative_eq!(&a * x, b, epsilon = 1e-12);
+/// ```
+pub fn gmres<F: DualNum<F> + Float>(
+    a: impl Fn(&DVector<F>) -> DVector<F>,
+    b: &DVector<F>,
+    preconditioner: Option<Preconditioner<F>>,
+    restart: usize,
+    tolerance: F,
+    max_iter: usize,
+) -> Result<DVector<F>, LinAlgError> {
+    let n = b.len();
+    let m = restart.clamp(1, n.max(1));
+    let precondition = |v: &DVector<F>| preconditioner.map_or_else(|| v.clone(), |p| p(v));
+    let mut x = DVector::zeros(n);
+    let b_norm = norm(b);
+    if b_norm.is_zero() {
+        return Ok(x);
+    }
+    let tolerance = tolerance * b_norm;
+
+    let mut iterations = 0;
+    loop {
+        let r = b - a(&x);
+        let beta = norm(&r);
+        if beta <= tolerance {
+            return Ok(x);
+        }
+        if iterations >= max_iter {
+            return Err(not_converged(iterations, beta / b_norm));
+        }
+
+        let mut v = vec![r / beta];
+        let mut z = Vec::with_capacity(m);
+        let mut h = DMatrix::zeros(m + 1, m);
+        let mut rotations: Vec<(F, F)> = Vec::with_capacity(m);
+        let mut g = DVector::zeros(m + 1);
+        g[0] = beta;
+        let mut k = 0;
+        while k < m && iterations < max_iter {
+            z.push(precondition(&v[k]));
+            let mut w = a(&z[k]);
+            for (i, vi) in v.iter().enumerate() {
+                h[(i, k)] = dot(&w, vi);
+                w -= vi * h[(i, k)];
+            }
+            h[(k + 1, k)] = norm(&w);
+            if !h[(k + 1, k)].is_zero() {
+                v.push(w / h[(k + 1, k)]);
+            }
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (h0, h1) = (h[(i, k)], h[(i + 1, k)]);
+                h[(i, k)] = c * h0 + s * h1;
+                h[(i + 1, k)] = c * h1 - s * h0;
+            }
+            let (h0, h1) = (h[(k, k)], h[(k + 1, k)]);
+            let d = h0.hypot(h1);
+            let (c, s) = (h0 / d, h1 / d);
+            rotations.push((c, s));
+            h[(k, k)] = d;
+            h[(k + 1, k)] = F::zero();
+            g[k + 1] = -s * g[k];
+            g[k] = c * g[k];
+
+            k += 1;
+            iterations += 1;
+            if g[k].abs() <= tolerance || v.len() == k {
+                break;
+            }
+        }
+
+        // solve the upper triangular least-squares system and update the solution
+        let mut y = DVector::zeros(k);
+        for i in (0..k).rev() {
+            y[i] = (i + 1..k).fold(g[i], |acc, j| acc - h[(i, j)] * y[j]) / h[(i, i)];
+        }
+        for (zi, &yi) in z.iter().zip(y.iter()) {
+            x += zi * yi;
+        }
+    }
+}
+
+/// Solve the linear system `a(x) = b` using the biconjugate gradient stabilized method.
+///
+/// The matrix is only accessed through matrix-vector products `a(x)`. The optional preconditioner
+/// is applied from the right. The iteration stops if the norm of the residual is smaller than
+/// `tolerance` times the norm of `b`.
+/// ```
+/// # use num_dual::linalg::bicgstab;
+/// # use nalgebra::{dmatrix, dvector, DVector};
+/// # use approx::assert_relative_eq;
+/// let a = dmatrix![4.0, 1.0, 0.0; 1.0, 3.0, 1.0; 0.0, 1.0, 2.0];
+/// let b = dvector![1.0, 2.0, 3.0];
+/// // Jacobi preconditioner
+/// let jacobi = |r: &DVector<f64>| r.component_div(&a.diagonal());
+/// let x = bicgstab(|x| &a * x, &b, Some(&jacobi), 1e-12, 100).unwrap();
+/// assert_relative_eq!(&a * x, b, epsilon = 1e-12);
+/// ```
+pub fn bicgstab<F: DualNum<F> + Float>(
+    a: impl Fn(&DVector<F>) -> DVector<F>,
+    b: &DVector<F>,
+    preconditioner: Option<Preconditioner<F>>,
+    tolerance: F,
+    max_iter: usize,
+) -> Result<DVector<F>, LinAlgError> {
+    let n = b.len();
+    let precondition = |v: &DVector<F>| preconditioner.map_or_else(|| v.clone(), |p| p(v));
+    let mut x = DVector::zeros(n);
+    let b_norm = norm(b);
+    if b_norm.is_zero() {
+        return Ok(x);
+    }
+    let tolerance = tolerance * b_norm;
+
+    let mut r = b.clone();
+    let r0 = r.clone();
+    let (mut rho, mut alpha, mut omega) = (F::one(), F::one(), F::one());
+    let mut v = DVector::zeros(n);
+    let mut p = DVector::zeros(n);
+    for i in 0..max_iter {
+        let rho_new = dot(&r0, &r);
+        if rho_new.is_zero() {
+            return Err(not_converged(i, norm(&r) / b_norm));
+        }
+        let beta = rho_new / rho * alpha / omega;
+        p = &r + (p - &v * omega) * beta;
+        let p_hat = precondition(&p);
+        v = a(&p_hat);
+        let r0v = dot(&r0, &v);
+        if r0v.is_zero() {
+            return Err(not_converged(i, norm(&r) / b_norm));
+        }
+        alpha = rho_new / r0v;
+        let s = &r - &v * alpha;
+        if norm(&s) <= tolerance {
+            return Ok(x + p_hat * alpha);
+        }
+        let s_hat = precondition(&s);
+        let t = a(&s_hat);
+        let tt = dot(&t, &t);
+        if tt.is_zero() {
+            return Err(not_converged(i, norm(&s) / b_norm));
+        }
+        omega = dot(&t, &s) / tt;
+        x += p_hat * alpha + s_hat * omega;
+        r = s - t * omega;
+        if norm(&r) <= tolerance {
+            return Ok(x);
+        }
+        rho = rho_new;
+    }
+    Err(not_converged(max_iter, norm(&r) / b_norm))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Dual64;
+    use crate::{Derivative, Dual64, DualDVec64};
     use approx::assert_abs_diff_eq;
-    use nalgebra::{dmatrix, dvector};
+    use nalgebra::{DMatrix, dmatrix, dvector};
 
     #[test]
     fn test_solve_f64() {
@@ -328,6 +718,101 @@ mod tests {
         assert_eq!((x[0].re, x[0].eps, x[1].re, x[1].eps), (1.0, 2.0, 2.0, 1.0));
     }
 
+    #[test]
+    fn test_solve_full_pivoting() {
+        let a = dmatrix![1e-3, 2.0, 1.0; 3.0, 1e3, 4.0; 5.0, 6.0, 1e-2];
+        let b = dvector![1.0, 2.0, 3.0];
+        let lu = LU::new(a.clone()).unwrap();
+        let lu_full = LU::with_options(a.clone(), 0.0, Pivoting::Full).unwrap();
+        assert_abs_diff_eq!(lu.determinant(), lu_full.determinant(), epsilon = 1e-10);
+        assert_abs_diff_eq!(lu.solve(&b), lu_full.solve(&b), epsilon = 1e-14);
+        assert_abs_diff_eq!(&a * lu_full.solve(&b), b, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            &a * lu_full.inverse(),
+            DMatrix::identity(3, 3),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_singular() {
+        let a = dmatrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 1.0, 0.0, 1.0];
+        let Err(LinAlgError::Singular {
+            column,
+            pivot,
+            rcond,
+        }) = LU::new(a)
+        else {
+            panic!("LU decomposition of a singular matrix succeeded.")
+        };
+        assert_eq!(column, 2);
+        assert_eq!(pivot, 0.0);
+        assert_eq!(rcond, 0.0);
+
+        let a = dmatrix![
+            Dual64::new(1.0, 1.0), Dual64::new(2.0, 0.0);
+            Dual64::new(1.0, 0.0), Dual64::new(2.0 + 1e-12, 0.0)
+        ];
+        assert!(LU::new(a.clone()).is_ok());
+        let Err(LinAlgError::Singular { column, pivot, .. }) =
+            LU::with_options(a, 1e-8, Pivoting::Partial)
+        else {
+            panic!("LU decomposition of an ill-conditioned matrix succeeded.")
+        };
+        assert_eq!(column, 1);
+        assert!(pivot < 1e-8);
+    }
+
+    #[test]
+    fn test_iterative() {
+        let n = 50;
+        let a = DMatrix::from_fn(n, n, |i, j| match i as isize - j as isize {
+            0 => 4.0,
+            -1 => -1.5,
+            1 => -0.5,
+            _ => 0.0,
+        });
+        let b = DVector::from_fn(n, |i, _| (i as f64).sin());
+        let jacobi = |r: &DVector<f64>| r / 4.0;
+        for solver in [
+            Krylov::Gmres {
+                restart: 8,
+                tolerance: 1e-12,
+                max_iter: 200,
+            },
+            Krylov::BiCgStab {
+                tolerance: 1e-12,
+                max_iter: 200,
+            },
+        ] {
+            let x = solver.solve(|x| &a * x, &b, None).unwrap();
+            assert_abs_diff_eq!(&a * x, b, epsilon = 1e-10);
+            let x = solver.solve(|x| &a * x, &b, Some(&jacobi)).unwrap();
+            assert_abs_diff_eq!(&a * x, b, epsilon = 1e-10);
+        }
+
+        let Err(LinAlgError::NotConverged {
+            iterations,
+            residual,
+        }) = gmres(|x| &a * x, &b, None, 2, 1e-12, 3)
+        else {
+            panic!("GMRES converged in too few iterations.")
+        };
+        assert_eq!(iterations, 3);
+        assert!(residual > 1e-12);
+
+        // breakdown for a singular system
+        let Err(LinAlgError::NotConverged {
+            iterations,
+            residual,
+        }) = bicgstab(|x| x * 0.0, &b, None, 1e-12, 100)
+        else {
+            panic!("BiCGSTAB converged for a singular system.")
+        };
+        assert_eq!(iterations, 0);
+        assert_eq!(residual, 1.0);
+    }
+
     #[test]
     fn test_eig_f64_2() {
         let a = dmatrix![2.0, 2.0; 2.0, 5.0];
@@ -419,6 +904,34 @@ mod tests {
         assert_abs_diff_eq!(v[(1, 0)].eps, v1[1].eps, epsilon = 1e-14);
     }
 
+    #[test]
+    fn test_eig_dual_dvec64() {
+        let a = [[2.0, 2.0, 7.0], [2.0, 5.0, 9.0], [7.0, 9.0, 2.0]];
+        let da = [[1.0, 2.0, 0.0], [2.0, 3.0, -1.0], [0.0, -1.0, 0.5]];
+        let a_dual = DMatrix::from_fn(3, 3, |i, j| Dual64::new(a[i][j], da[i][j]));
+        let a_dvec = DMatrix::from_fn(3, 3, |i, j| {
+            DualDVec64::new(a[i][j], Derivative::some(dvector![da[i][j]]))
+        });
+        let (l, v) = jacobi_eigenvalue(a_dual.clone(), 200);
+        let (l1, v1) = jacobi_eigenvalue(a_dvec.clone(), 200);
+        for i in 0..3 {
+            assert_abs_diff_eq!(l[i].re, l1[i].re, epsilon = 1e-14);
+            assert_abs_diff_eq!(l[i].eps, l1[i].eps.0.as_ref().unwrap()[0], epsilon = 1e-12);
+            for j in 0..3 {
+                assert_abs_diff_eq!(v[(i, j)].re, v1[(i, j)].re, epsilon = 1e-14);
+                assert_abs_diff_eq!(
+                    v[(i, j)].eps,
+                    v1[(i, j)].eps.0.as_ref().unwrap()[0],
+                    epsilon = 1e-12
+                );
+            }
+        }
+        let (e, u) = smallest_ev(a_dvec);
+        assert_abs_diff_eq!(e.re, l[0].re, epsilon = 1e-14);
+        assert_abs_diff_eq!(e.eps.0.unwrap()[0], l[0].eps, epsilon = 1e-12);
+        assert_abs_diff_eq!(u[2].re, v[(2, 0)].re, epsilon = 1e-14);
+    }
+
     #[test]
     fn test_norm_f64() {
         let v = dvector![3.0, 4.0];
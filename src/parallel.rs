@@ -0,0 +1,309 @@
+use crate::*;
+use nalgebra::{Const, DMatrix, DVector, Dyn, OVector, U1};
+use rayon::prelude::*;
+
+/// Multithreaded evaluation of gradients, hessians, partial (Nx1) hessians, and (rectangular) Jacobians.
+///
+/// The methods are equivalent to the ones in [`Gradients`], but the independent directional sweeps
+/// that are required for dynamically sized input vectors are distributed over the [`rayon`] thread
+/// pool. Every entry of the results is calculated by exactly one sweep, so the results do not depend
+/// on the number of threads or the order of execution. For statically sized input vectors, the
+/// derivatives are calculated in a single function evaluation, and the methods fall back to
+/// the implementation in [`Gradients`].
+/// ```
+/// # use num_dual::{DualNum, ParallelGradients};
+/// # use nalgebra::{OVector, DefaultAllocator, allocator::Allocator, dvector};
+/// # use approx::assert_relative_eq;
+/// fn foo<D: DualNum<f64> + Copy, N: ParallelGradients>(x: OVector<D, N>, n: &D) -> D
+/// where
+///     DefaultAllocator: Allocator<N>,
+/// {
+///     x.dot(&x).sqrt() - n
+/// }
+///
+/// let x = dvector![1.0, 5.0, 5.0, 7.0];
+/// let (f, grad, hess) = ParallelGradients::par_hessian(foo, &x, &10.0);
+/// assert_eq!(f, 0.0);
+/// assert_relative_eq!(grad, dvector![0.1, 0.5, 0.5, 0.7]);
+/// assert_relative_eq!(hess[(1, 2)], -0.025);
+/// ```
+pub trait ParallelGradients: Gradients
+where
+    DefaultAllocator: Allocator<Self>,
+{
+    fn par_gradient<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<Self::Dual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        args: &A::Inner,
+    ) -> (T, OVector<T, Self>)
+    where
+        G: Fn(OVector<Self::Dual<T, F>, Self>, &A) -> Self::Dual<T, F> + Sync;
+
+    fn par_hessian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<Self::Dual2<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        args: &A::Inner,
+    ) -> (T, OVector<T, Self>, OMatrix<T, Self, Self>)
+    where
+        G: Fn(OVector<Self::Dual2<T, F>, Self>, &A) -> Self::Dual2<T, F> + Sync,
+        DefaultAllocator: Allocator<Self, Self>;
+
+    fn par_partial_hessian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<Self::HyperDual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        y: T,
+        args: &A::Inner,
+    ) -> (T, OVector<T, Self>, T, OVector<T, Self>)
+    where
+        G: Fn(
+                OVector<Self::HyperDual<T, F>, Self>,
+                Self::HyperDual<T, F>,
+                &A,
+            ) -> Self::HyperDual<T, F>
+            + Sync;
+
+    fn par_jacobian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<Self::Dual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        args: &A::Inner,
+    ) -> (OVector<T, M>, OMatrix<T, M, Self>)
+    where
+        G: Fn(OVector<Self::Dual<T, F>, Self>, &A) -> OVector<Self::Dual<T, F>, M> + Sync,
+        DefaultAllocator: Allocator<M> + Allocator<M, Self>;
+}
+
+impl<const N: usize> ParallelGradients for Const<N> {
+    fn par_gradient<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<Self::Dual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        args: &A::Inner,
+    ) -> (T, OVector<T, Self>)
+    where
+        G: Fn(OVector<Self::Dual<T, F>, Self>, &A) -> Self::Dual<T, F> + Sync,
+    {
+        Self::gradient(g, x, args)
+    }
+
+    fn par_hessian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<Self::Dual2<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        args: &A::Inner,
+    ) -> (T, OVector<T, Self>, OMatrix<T, Self, Self>)
+    where
+        G: Fn(OVector<Self::Dual2<T, F>, Self>, &A) -> Self::Dual2<T, F> + Sync,
+    {
+        Self::hessian(g, x, args)
+    }
+
+    fn par_partial_hessian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<Self::HyperDual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        y: T,
+        args: &A::Inner,
+    ) -> (T, OVector<T, Self>, T, OVector<T, Self>)
+    where
+        G: Fn(
+                OVector<Self::HyperDual<T, F>, Self>,
+                Self::HyperDual<T, F>,
+                &A,
+            ) -> Self::HyperDual<T, F>
+            + Sync,
+    {
+        Self::partial_hessian(g, x, y, args)
+    }
+
+    fn par_jacobian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<Self::Dual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &OVector<T, Self>,
+        args: &A::Inner,
+    ) -> (OVector<T, M>, OMatrix<T, M, Self>)
+    where
+        G: Fn(OVector<Self::Dual<T, F>, Self>, &A) -> OVector<Self::Dual<T, F>, M> + Sync,
+        DefaultAllocator: Allocator<M> + Allocator<M, Self>,
+    {
+        Self::jacobian(g, x, args)
+    }
+}
+
+impl ParallelGradients for Dyn {
+    fn par_gradient<G, T: DualNumCopy<F>, F: DualNumFloat, A: DualStruct<Dual<T, F>, F> + Sync>(
+        g: G,
+        x: &DVector<T>,
+        args: &A::Inner,
+    ) -> (T, DVector<T>)
+    where
+        G: Fn(DVector<Dual<T, F>>, &A) -> Dual<T, F> + Sync,
+    {
+        let n = x.len();
+        let args = A::from_inner(args);
+        if n == 0 {
+            return (g(x.map(Dual::from_re), &args).re, DVector::zeros(0));
+        }
+        let res: Vec<_> = (0..n)
+            .into_par_iter()
+            .map(|i| {
+                let mut x = x.map(Dual::from_re);
+                x[i].eps = T::one();
+                g(x, &args)
+            })
+            .collect();
+        let re = res[n - 1].re;
+        (re, DVector::from_iterator(n, res.iter().map(|r| r.eps)))
+    }
+
+    fn par_hessian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<HyperDual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &DVector<T>,
+        args: &A::Inner,
+    ) -> (T, DVector<T>, DMatrix<T>)
+    where
+        G: Fn(DVector<HyperDual<T, F>>, &A) -> HyperDual<T, F> + Sync,
+    {
+        let n = x.len();
+        let args = A::from_inner(args);
+        if n == 0 {
+            let re = g(x.map(HyperDual::from_re), &args).re;
+            return (re, DVector::zeros(0), DMatrix::zeros(0, 0));
+        }
+        let pairs: Vec<_> = (0..n).flat_map(|i| (i..n).map(move |j| (i, j))).collect();
+        let res: Vec<_> = pairs
+            .par_iter()
+            .map(|&(i, j)| {
+                let mut x = x.map(HyperDual::from_re);
+                x[i].eps1 = T::one();
+                x[j].eps2 = T::one();
+                g(x, &args)
+            })
+            .collect();
+        let re = res[res.len() - 1].re;
+        let mut grad = DVector::zeros(n);
+        let mut hessian = DMatrix::zeros(n, n);
+        for (&(i, j), r) in pairs.iter().zip(&res) {
+            if i == j {
+                grad[i] = r.eps1;
+            }
+            hessian[(i, j)] = r.eps1eps2;
+            hessian[(j, i)] = r.eps1eps2;
+        }
+        (re, grad, hessian)
+    }
+
+    fn par_partial_hessian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        A: DualStruct<HyperDual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &DVector<T>,
+        y: T,
+        args: &A::Inner,
+    ) -> (T, DVector<T>, T, DVector<T>)
+    where
+        G: Fn(DVector<HyperDual<T, F>>, HyperDual<T, F>, &A) -> HyperDual<T, F> + Sync,
+    {
+        let n = x.len();
+        let args = A::from_inner(args);
+        let y = HyperDual::from_re(y).derivative2();
+        if n == 0 {
+            let r = g(x.map(HyperDual::from_re), y, &args);
+            return (r.re, DVector::zeros(0), r.eps2, DVector::zeros(0));
+        }
+        let res: Vec<_> = (0..n)
+            .into_par_iter()
+            .map(|i| {
+                let mut x = x.map(HyperDual::from_re);
+                x[i].eps1 = T::one();
+                g(x, y, &args)
+            })
+            .collect();
+        let (re, grad_y) = (res[n - 1].re, res[n - 1].eps2);
+        let grad_x = DVector::from_iterator(n, res.iter().map(|r| r.eps1));
+        let hessian = DVector::from_iterator(n, res.iter().map(|r| r.eps1eps2));
+        (re, grad_x, grad_y, hessian)
+    }
+
+    fn par_jacobian<
+        G,
+        T: DualNumCopy<F>,
+        F: DualNumFloat,
+        M: Dim,
+        A: DualStruct<Dual<T, F>, F> + Sync,
+    >(
+        g: G,
+        x: &DVector<T>,
+        args: &A::Inner,
+    ) -> (OVector<T, M>, OMatrix<T, M, Dyn>)
+    where
+        G: Fn(DVector<Dual<T, F>>, &A) -> OVector<Dual<T, F>, M> + Sync,
+        DefaultAllocator: Allocator<M> + Allocator<M, Dyn>,
+    {
+        let n = x.len();
+        let args = A::from_inner(args);
+        let columns: Vec<_> = (0..n)
+            .into_par_iter()
+            .map(|i| {
+                let mut x = x.map(Dual::from_re);
+                x[i].eps = T::one();
+                g(x, &args).iter().copied().collect::<Vec<_>>()
+            })
+            .collect();
+        let f = match columns.last() {
+            Some(res) => {
+                let m = M::from_usize(res.len());
+                OVector::from_iterator_generic(m, U1, res.iter().map(|r| r.re))
+            }
+            None => g(x.map(Dual::from_re), &args).map(|r| r.re),
+        };
+        let (m, _) = f.shape_generic();
+        let jac = OMatrix::from_fn_generic(m, Dyn(n), |i, j| columns[j][i].eps);
+        (f, jac)
+    }
+}
@@ -0,0 +1,477 @@
+//! Conversion between containers of dual numbers and separate containers for every part
+//! (real part and derivatives) of the dual numbers.
+use crate::*;
+use nalgebra::{Dim, U1};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array, ArrayD, Dimension, IxDyn};
+use num_traits::Zero;
+
+/// Decomposition of a dual number into its parts.
+///
+/// Every part (e.g., `re`, `eps`, `v1`, `v2`, `eps1eps2`) is represented by a [`Vec`] of inner
+/// numbers. Scalar parts consist of a single element, vector parts of one element per direction,
+/// and matrix parts are stored in column-major order. Derivatives that are not initialized
+/// (`None`) are represented by empty vectors.
+/// ```
+/// # use num_dual::{DualParts, DualSVec64, Derivative};
+/// # use nalgebra::SVector;
+/// let x = DualSVec64::new(2.0, Derivative::some(SVector::from([1.0, 3.0])));
+/// assert_eq!(DualSVec64::<2>::PART_NAMES, ["re", "eps"]);
+/// assert_eq!(x.to_parts(), vec![vec![2.0], vec![1.0, 3.0]]);
+/// assert_eq!(DualSVec64::from_parts(&x.to_parts()), x);
+/// ```
+pub trait DualParts<F>: DualNum<F, Inner: DualNum<F>> {
+    /// Names of the parts as they are used for the fields of the struct.
+    const PART_NAMES: &'static [&'static str];
+    /// Number of dimensions of every part (0 for scalars, 1 for vectors, 2 for matrices).
+    const PART_NDIM: &'static [usize];
+
+    /// Shapes of the parts. Scalar parts have an empty shape. The dynamic dimensions of
+    /// uninitialized derivatives are reported as 0.
+    fn part_shapes(&self) -> Vec<Vec<usize>>;
+
+    /// Split the dual number into its parts.
+    fn to_parts(&self) -> Vec<Vec<Self::Inner>>;
+
+    /// Assemble a dual number from its parts. Empty parts are interpreted as uninitialized
+    /// derivatives (or zero for scalar parts). Dynamic dimensions are determined from the
+    /// lengths of the parts.
+    ///
+    /// # Panics
+    /// Panics if the number of parts or the length of a part does not match the dual number type.
+    fn from_parts(parts: &[Vec<Self::Inner>]) -> Self;
+
+    /// Split the dual number recursively (including all inner dual numbers) into real numbers.
+    fn to_reals(&self) -> Vec<F>;
+
+    /// Assemble a dual number with the same structure (i.e., the same initialized derivatives
+    /// and dynamic dimensions) as `self` from real numbers in the order of
+    /// [`DualParts::to_reals`].
+    ///
+    /// # Panics
+    /// Panics if `reals` contains too few elements.
+    fn with_reals(&self, reals: &mut impl Iterator<Item = F>) -> Self;
+}
+
+fn to_reals<D: DualParts<F, Inner: DualParts<F>>, F>(x: &D) -> Vec<F> {
+    x.to_parts()
+        .iter()
+        .flatten()
+        .flat_map(|x| x.to_reals())
+        .collect()
+}
+
+fn with_reals<D: DualParts<F, Inner: DualParts<F>>, F>(
+    x: &D,
+    reals: &mut impl Iterator<Item = F>,
+) -> D {
+    let parts: Vec<Vec<_>> = x
+        .to_parts()
+        .iter()
+        .map(|part| part.iter().map(|x| x.with_reals(reals)).collect())
+        .collect();
+    D::from_parts(&parts)
+}
+
+macro_rules! impl_parts_float {
+    ($float:ty) => {
+        impl DualParts<$float> for $float {
+            const PART_NAMES: &'static [&'static str] = &["re"];
+            const PART_NDIM: &'static [usize] = &[0];
+
+            fn part_shapes(&self) -> Vec<Vec<usize>> {
+                vec![vec![]]
+            }
+
+            fn to_parts(&self) -> Vec<Vec<$float>> {
+                vec![vec![*self]]
+            }
+
+            fn from_parts(parts: &[Vec<$float>]) -> Self {
+                check_parts(parts, Self::PART_NAMES);
+                scalar_part(&parts[0])
+            }
+
+            fn to_reals(&self) -> Vec<$float> {
+                vec![*self]
+            }
+
+            fn with_reals(&self, reals: &mut impl Iterator<Item = $float>) -> Self {
+                reals.next().expect("too few real numbers")
+            }
+        }
+    };
+}
+
+impl_parts_float!(f32);
+impl_parts_float!(f64);
+
+fn check_parts<P>(parts: &[P], names: &[&str]) {
+    assert_eq!(
+        parts.len(),
+        names.len(),
+        "expected {} parts ({}), got {}",
+        names.len(),
+        names.join(", "),
+        parts.len()
+    );
+}
+
+fn scalar_part<T: Clone + Zero>(part: &[T]) -> T {
+    match part {
+        [] => T::zero(),
+        [x] => x.clone(),
+        _ => panic!("expected a scalar part, got {} elements", part.len()),
+    }
+}
+
+macro_rules! impl_parts_scalar {
+    ($struct:ident, [$($part:ident),+]) => {
+        impl<T: DualParts<F>, F: DualNumFloat> DualParts<F> for $struct<T, F> {
+            const PART_NAMES: &'static [&'static str] = &[$(stringify!($part)),+];
+            const PART_NDIM: &'static [usize] = &[$(impl_parts_scalar!(@zero $part)),+];
+
+            fn part_shapes(&self) -> Vec<Vec<usize>> {
+                vec![vec![]; Self::PART_NAMES.len()]
+            }
+
+            fn to_parts(&self) -> Vec<Vec<T>> {
+                vec![$(vec![self.$part.clone()]),+]
+            }
+
+            fn from_parts(parts: &[Vec<T>]) -> Self {
+                check_parts(parts, Self::PART_NAMES);
+                let mut parts = parts.iter();
+                Self::new($(impl_parts_scalar!(@next parts $part)),+)
+            }
+
+            fn to_reals(&self) -> Vec<F> {
+                to_reals(self)
+            }
+
+            fn with_reals(&self, reals: &mut impl Iterator<Item = F>) -> Self {
+                with_reals(self, reals)
+            }
+        }
+    };
+    (@zero $part:ident) => { 0 };
+    (@next $parts:ident $part:ident) => { scalar_part($parts.next().unwrap()) };
+}
+
+impl_parts_scalar!(Real, [re]);
+impl_parts_scalar!(Dual, [re, eps]);
+impl_parts_scalar!(Dual2, [re, v1, v2]);
+impl_parts_scalar!(Dual3, [re, v1, v2, v3]);
+impl_parts_scalar!(HyperDual, [re, eps1, eps2, eps1eps2]);
+impl_parts_scalar!(
+    HyperHyperDual,
+    [
+        re,
+        eps1,
+        eps2,
+        eps3,
+        eps1eps2,
+        eps1eps3,
+        eps2eps3,
+        eps1eps2eps3
+    ]
+);
+
+/// Determine a (possibly dynamic) dimension from the length of a part.
+fn dim<D: Dim>(len: usize) -> usize {
+    D::try_to_usize().unwrap_or(len)
+}
+
+impl<T: DualParts<F>, F: DualNumFloat, D: Dim> DualParts<F> for DualVec<T, F, D>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    const PART_NAMES: &'static [&'static str] = &["re", "eps"];
+    const PART_NDIM: &'static [usize] = &[0, 1];
+
+    fn part_shapes(&self) -> Vec<Vec<usize>> {
+        vec![vec![], vec![self.eps.shape().0]]
+    }
+
+    fn to_parts(&self) -> Vec<Vec<T>> {
+        vec![vec![self.re.clone()], self.eps.to_vec()]
+    }
+
+    fn from_parts(parts: &[Vec<T>]) -> Self {
+        check_parts(parts, Self::PART_NAMES);
+        let n = dim::<D>(parts[1].len());
+        Self::new(
+            scalar_part(&parts[0]),
+            Derivative::from_slice(n, 1, &parts[1]),
+        )
+    }
+
+    fn to_reals(&self) -> Vec<F> {
+        to_reals(self)
+    }
+
+    fn with_reals(&self, reals: &mut impl Iterator<Item = F>) -> Self {
+        with_reals(self, reals)
+    }
+}
+
+impl<T: DualParts<F>, F: DualNumFloat, D: Dim> DualParts<F> for Dual2Vec<T, F, D>
+where
+    DefaultAllocator: Allocator<U1, D> + Allocator<D, D>,
+{
+    const PART_NAMES: &'static [&'static str] = &["re", "v1", "v2"];
+    const PART_NDIM: &'static [usize] = &[0, 1, 2];
+
+    fn part_shapes(&self) -> Vec<Vec<usize>> {
+        let (n, _) = self.v2.shape();
+        let n = n.max(self.v1.shape().1);
+        vec![vec![], vec![n], vec![n, n]]
+    }
+
+    fn to_parts(&self) -> Vec<Vec<T>> {
+        vec![vec![self.re.clone()], self.v1.to_vec(), self.v2.to_vec()]
+    }
+
+    fn from_parts(parts: &[Vec<T>]) -> Self {
+        check_parts(parts, Self::PART_NAMES);
+        let n = if parts[1].is_empty() {
+            dim::<D>(parts[2].len().isqrt())
+        } else {
+            dim::<D>(parts[1].len())
+        };
+        Self::new(
+            scalar_part(&parts[0]),
+            Derivative::from_slice(1, n, &parts[1]),
+            Derivative::from_slice(n, n, &parts[2]),
+        )
+    }
+
+    fn to_reals(&self) -> Vec<F> {
+        to_reals(self)
+    }
+
+    fn with_reals(&self, reals: &mut impl Iterator<Item = F>) -> Self {
+        with_reals(self, reals)
+    }
+}
+
+impl<T: DualParts<F>, F: DualNumFloat, M: Dim, N: Dim> DualParts<F> for HyperDualVec<T, F, M, N>
+where
+    DefaultAllocator: Allocator<M> + Allocator<U1, N> + Allocator<M, N>,
+{
+    const PART_NAMES: &'static [&'static str] = &["re", "eps1", "eps2", "eps1eps2"];
+    const PART_NDIM: &'static [usize] = &[0, 1, 1, 2];
+
+    fn part_shapes(&self) -> Vec<Vec<usize>> {
+        let (m, n) = self.eps1eps2.shape();
+        let m = m.max(self.eps1.shape().0);
+        let n = n.max(self.eps2.shape().1);
+        vec![vec![], vec![m], vec![n], vec![m, n]]
+    }
+
+    fn to_parts(&self) -> Vec<Vec<T>> {
+        vec![
+            vec![self.re.clone()],
+            self.eps1.to_vec(),
+            self.eps2.to_vec(),
+            self.eps1eps2.to_vec(),
+        ]
+    }
+
+    fn from_parts(parts: &[Vec<T>]) -> Self {
+        check_parts(parts, Self::PART_NAMES);
+        let (mut m, mut n) = (dim::<M>(parts[1].len()), dim::<N>(parts[2].len()));
+        if !parts[3].is_empty() {
+            // The dimensions of uninitialized gradients are determined from the partial Hessian.
+            if m == 0 && n > 0 {
+                m = parts[3].len() / n;
+            } else if n == 0 && m > 0 {
+                n = parts[3].len() / m;
+            }
+        }
+        Self::new(
+            scalar_part(&parts[0]),
+            Derivative::from_slice(m, 1, &parts[1]),
+            Derivative::from_slice(1, n, &parts[2]),
+            Derivative::from_slice(m, n, &parts[3]),
+        )
+    }
+
+    fn to_reals(&self) -> Vec<F> {
+        to_reals(self)
+    }
+
+    fn with_reals(&self, reals: &mut impl Iterator<Item = F>) -> Self {
+        with_reals(self, reals)
+    }
+}
+
+/// Split the parts of all elements of a vector of dual numbers. The result contains, for every part,
+/// a vector of all elements, for every entry of the (flattened) part. Uninitialized derivatives are
+/// filled with zeros.
+#[expect(clippy::type_complexity)]
+fn split<D: DualParts<F>, F>(elements: &[D]) -> (Vec<Vec<usize>>, Vec<Vec<Vec<D::Inner>>>) {
+    let mut shapes: Vec<Vec<usize>> = D::PART_NDIM.iter().map(|&n| vec![0; n]).collect();
+    for x in elements {
+        for (shape, s) in shapes.iter_mut().zip(x.part_shapes()) {
+            shape.iter_mut().zip(s).for_each(|(a, b)| *a = (*a).max(b));
+        }
+    }
+    let parts = elements.iter().map(|x| x.to_parts()).collect::<Vec<_>>();
+    let split = shapes
+        .iter()
+        .enumerate()
+        .map(|(p, shape)| {
+            (0..shape.iter().product())
+                .map(|k| {
+                    parts
+                        .iter()
+                        .map(|x| x[p].get(k).cloned().unwrap_or_else(D::Inner::zero))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+    (shapes, split)
+}
+
+/// Split a matrix of dual numbers into matrices of their parts.
+///
+/// The result contains one entry per part of the dual number ([`DualParts::PART_NAMES`]) with
+/// one matrix per (column-major flattened) element of the part, e.g., the real part and the
+/// `N` directional derivatives of a [`DualVec`]. Uninitialized derivatives are filled with zeros.
+/// ```
+/// # use num_dual::{split_parts, from_parts, DualSVec64, Derivative};
+/// # use nalgebra::{SVector, vector};
+/// let x = vector![
+///     DualSVec64::new(1.0, Derivative::some(SVector::from([1.0, 0.0]))),
+///     DualSVec64::new(2.0, Derivative::some(SVector::from([0.0, 1.0]))),
+///     DualSVec64::new(3.0, Derivative::none()),
+/// ];
+/// let parts = split_parts(&x);
+/// assert_eq!(parts[0], [vector![1.0, 2.0, 3.0]]);
+/// assert_eq!(parts[1], [vector![1.0, 0.0, 0.0], vector![0.0, 1.0, 0.0]]);
+///
+/// let y: SVector<DualSVec64<2>, 3> = from_parts(&parts);
+/// assert_eq!(y[1], x[1]);
+/// ```
+pub fn split_parts<D: DualParts<F>, F, R: Dim, C: Dim>(
+    m: &OMatrix<D, R, C>,
+) -> Vec<Vec<OMatrix<D::Inner, R, C>>>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    let (r, c) = m.shape_generic();
+    let (_, parts) = split(m.as_slice());
+    parts
+        .into_iter()
+        .map(|part| {
+            part.into_iter()
+                .map(|v| OMatrix::from_iterator_generic(r, c, v))
+                .collect()
+        })
+        .collect()
+}
+
+/// Assemble a matrix of dual numbers from matrices of their parts. The inverse of [`split_parts`].
+///
+/// # Panics
+/// Panics if no parts are provided or if the parts do not match the dual number type.
+pub fn from_parts<D: DualParts<F>, F, R: Dim, C: Dim>(
+    parts: &[Vec<OMatrix<D::Inner, R, C>>],
+) -> OMatrix<D, R, C>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    let (r, c) = parts
+        .first()
+        .and_then(|p| p.first())
+        .expect("the real part is missing")
+        .shape_generic();
+    OMatrix::from_fn_generic(r, c, |i, j| {
+        let p: Vec<Vec<_>> = parts
+            .iter()
+            .map(|p| p.iter().map(|m| m[(i, j)].clone()).collect())
+            .collect();
+        D::from_parts(&p)
+    })
+}
+
+/// Split an array of dual numbers into arrays of their parts.
+///
+/// The result contains one array per part of the dual number ([`DualParts::PART_NAMES`]). The
+/// shape of the part (e.g., `N` for the gradient of a [`DualVec`] or `M×N` for the partial Hessian
+/// of a [`HyperDualVec`]) is appended to the shape of the input array. Uninitialized derivatives are
+/// filled with zeros.
+/// ```
+/// # use num_dual::{split_parts_nd, from_parts_nd, Dual2SVec64, Derivative, DualNum};
+/// # use ndarray::{arr1, Array1, Ix1};
+/// let x = arr1(&[1.0, 2.0]).mapv(|x| Dual2SVec64::<2>::from_re(x).derivative(1).powi(2));
+/// let parts = split_parts_nd(&x);
+/// assert_eq!(parts[0].shape(), [2]);
+/// assert_eq!(parts[1].shape(), [2, 2]);
+/// assert_eq!(parts[2].shape(), [2, 2, 2]);
+/// assert_eq!(parts[1][[1, 1]], 4.0);
+/// assert_eq!(parts[2][[1, 1, 1]], 2.0);
+/// assert_eq!(parts[2][[1, 0, 1]], 0.0);
+///
+/// let y: Array1<Dual2SVec64<2>> = from_parts_nd(&parts).into_dimensionality::<Ix1>().unwrap();
+/// assert_eq!(y, x);
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn split_parts_nd<D: DualParts<F>, F, Ix: Dimension>(
+    a: &Array<D, Ix>,
+) -> Vec<ArrayD<D::Inner>> {
+    let elements: Vec<_> = a.iter().cloned().collect();
+    let (shapes, parts) = split(&elements);
+    shapes
+        .into_iter()
+        .zip(parts)
+        .map(|(shape, part)| {
+            let part_shape: Vec<_> = a.shape().iter().chain(&shape).copied().collect();
+            let n = a.len();
+            // Parts are flattened in column-major order, so the trailing axes are reversed.
+            let offsets: Vec<_> = shape
+                .iter()
+                .scan(1, |s, &d| {
+                    let o = *s;
+                    *s *= d;
+                    Some(o)
+                })
+                .collect();
+            ArrayD::from_shape_fn(IxDyn(&part_shape), |ix| {
+                let (outer, inner) = ix.slice().split_at(a.ndim());
+                let e = outer
+                    .iter()
+                    .zip(a.shape())
+                    .fold(0, |acc, (&i, &d)| acc * d + i);
+                let k: usize = inner.iter().zip(&offsets).map(|(i, o)| i * o).sum();
+                debug_assert!(e < n);
+                part[k][e].clone()
+            })
+        })
+        .collect()
+}
+
+/// Assemble an array of dual numbers from arrays of their parts. The inverse of [`split_parts_nd`].
+///
+/// # Panics
+/// Panics if the parts do not match the dual number type.
+#[cfg(feature = "ndarray")]
+pub fn from_parts_nd<D: DualParts<F>, F>(parts: &[ArrayD<D::Inner>]) -> ArrayD<D> {
+    check_parts(parts, D::PART_NAMES);
+    let shape = parts[0].shape().to_vec();
+    ArrayD::from_shape_fn(IxDyn(&shape), |ix| {
+        let p: Vec<Vec<_>> = parts
+            .iter()
+            .map(|p| {
+                let mut sub = p.view();
+                for &i in ix.slice() {
+                    sub = sub.index_axis_move(ndarray::Axis(0), i);
+                }
+                sub.reversed_axes().iter().cloned().collect()
+            })
+            .collect();
+        D::from_parts(&p)
+    })
+}
@@ -1,10 +1,18 @@
+use super::PyDualNumber;
+use super::dual2::{PyDual2_64, PyDual2_64Dyn};
+use super::dual3::PyDual3_64;
+use super::hyperdual::{PyHyperDual64, PyHyperDual64Dyn};
 use crate::*;
-use nalgebra::{DVector, SVector};
-use numpy::{PyArray, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
+use nalgebra::allocator::Allocator;
+use nalgebra::{Const, DVector, DefaultAllocator, Dim, Dyn, SVector, U1};
+use numpy::ndarray::{Array1, Array2, ArrayD};
+use numpy::{
+    IntoPyArray, PyArray, PyArray1, PyArray2, PyArrayDyn, PyReadonlyArrayDyn, PyReadwriteArrayDyn,
+};
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 
-#[pyclass(name = "Dual64")]
+#[pyclass(module = "num_dual", name = "Dual64")]
 #[derive(Clone, Debug)]
 /// Dual number using 64-bit-floats as fields.
 ///
@@ -46,9 +54,98 @@ impl PyDual64 {
 
 impl_dual_num!(PyDual64, Dual64, f64);
 
+macro_rules! impl_dual {
+    ($py_type_name:ident, $py_name:literal, $doc:literal, $field_type:ty, $inner:ty, $float:ty) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
+        #[derive(Clone)]
+        #[doc = $doc]
+        pub struct $py_type_name(Dual<$inner, $float>);
+
+        #[pymethods]
+        impl $py_type_name {
+            #[new]
+            fn new(re: $field_type, eps: $field_type) -> Self {
+                Dual::new(re.into(), eps.into()).into()
+            }
+
+            #[getter]
+            fn get_first_derivative(&self) -> $field_type {
+                self.0.eps.clone().into()
+            }
+        }
+
+        impl_dual_num!($py_type_name, Dual<$inner, $float>, $field_type, $float);
+    };
+}
+
+impl_dual!(
+    PyDual32,
+    "Dual32",
+    "Dual number using 32-bit-floats as fields.",
+    f32,
+    f32,
+    f32
+);
+impl_dual!(
+    PyDualDual64,
+    "DualDual64",
+    "Dual number using dual numbers as fields.",
+    PyDual64,
+    Dual64,
+    f64
+);
+impl_dual!(
+    PyDualDual2_64,
+    "DualDual2_64",
+    "Dual number using second order dual numbers as fields.",
+    PyDual2_64,
+    Dual2_64,
+    f64
+);
+impl_dual!(
+    PyDualDual3_64,
+    "DualDual3_64",
+    "Dual number using third order dual numbers as fields.",
+    PyDual3_64,
+    Dual3_64,
+    f64
+);
+impl_dual!(
+    PyDualHyperDual64,
+    "DualHyperDual64",
+    "Dual number using hyper-dual numbers as fields.",
+    PyHyperDual64,
+    HyperDual64,
+    f64
+);
+impl_dual!(
+    PyDualDual64Dyn,
+    "DualDual64Dyn",
+    "Dual number using dual numbers with a dynamic number of derivatives as fields.",
+    PyDual64Dyn,
+    DualDVec64,
+    f64
+);
+impl_dual!(
+    PyDualDual2_64Dyn,
+    "DualDual2_64Dyn",
+    "Dual number using second order dual numbers with a dynamic number of variables as fields.",
+    PyDual2_64Dyn,
+    Dual2DVec64,
+    f64
+);
+impl_dual!(
+    PyDualHyperDual64Dyn,
+    "DualHyperDual64Dyn",
+    "Dual number using hyper-dual numbers with a dynamic number of variables as fields.",
+    PyHyperDual64Dyn,
+    HyperDualDVec64,
+    f64
+);
+
 macro_rules! impl_dual_n {
-    ($py_type_name:ident, $n:literal) => {
-        #[pyclass(name = "DualSVec64")]
+    ($py_type_name:ident, $py_name:literal, $n:literal) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
         #[derive(Clone, Copy)]
         pub struct $py_type_name(DualSVec64<$n>);
 
@@ -64,12 +161,30 @@ macro_rules! impl_dual_n {
     };
 }
 
-#[pyclass(name = "Dual64Dyn")]
+#[pyclass(module = "num_dual", name = "Dual64Dyn")]
 #[derive(Clone)]
+/// Dual number with a dynamic number of derivatives and 64-bit-floats as fields.
+///
+/// Examples
+///
+/// Derivatives of the second derivative of f(x) = p x³ + q x w.r.t. the parameters p and q.
+///
+/// >>> from num_dual import Dual64Dyn, HyperDualDual64Dyn
+/// >>> p = HyperDualDual64Dyn.from_re(Dual64Dyn(3.0, [1.0, 0.0]))
+/// >>> q = HyperDualDual64Dyn.from_re(Dual64Dyn(2.0, [0.0, 1.0]))
+/// >>> x = HyperDualDual64Dyn(Dual64Dyn.from_re(2.0), Dual64Dyn.from_re(1.0), Dual64Dyn.from_re(1.0), Dual64Dyn.from_re(0.0))
+/// >>> f = p * x**3 + q * x
+/// >>> f.second_derivative
+/// 36 + [12, 0]ε
 pub struct PyDual64Dyn(DualDVec64);
 
 #[pymethods]
 impl PyDual64Dyn {
+    #[new]
+    fn new(re: f64, eps: Vec<f64>) -> Self {
+        DualDVec64::new(re, Derivative::some(DVector::from_vec(eps))).into()
+    }
+
     #[getter]
     pub fn get_first_derivative(&self) -> Option<Vec<f64>> {
         self.0.eps.0.as_ref().map(|eps| eps.data.as_vec().clone())
@@ -106,8 +221,123 @@ pub fn first_derivative(f: &Bound<'_, PyAny>, x: f64) -> PyResult<(f64, f64)> {
     crate::first_derivative(g, x)
 }
 
+/// Broadcast the result of a vectorized function to `shape` and return its elements in row-major
+/// order.
+fn broadcast_result<'py>(
+    res: &Bound<'py, PyAny>,
+    shape: &[usize],
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let numpy = res.py().import("numpy")?;
+    let res = numpy.call_method1("asarray", (res, "O"))?;
+    numpy
+        .call_method1("broadcast_to", (res, shape.to_vec()))?
+        .call_method0("ravel")?
+        .call_method0("tolist")?
+        .extract()
+}
+
+/// Convert an element of the result of a vectorized function.
+fn result_dual<D: DualNum<f64>, P: PyDualNumber<D>>(res: &Bound<'_, PyAny>) -> PyResult<D> {
+    super::extract_dual::<D, f64, P>(res).ok_or_else(|| {
+        PyErr::new::<PyTypeError, _>(format!(
+            "argument 'f' must return dual numbers of type {} or floats. For vector functions use 'jacobian' instead.",
+            P::NAME
+        ))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (f, x, vectorized=false))]
+/// Calculate the first derivative of a scalar, univariate function at many points.
+///
+/// Parameters
+/// ----------
+/// f : callable
+///     A scalar, univariate function.
+/// x : numpy.ndarray or [float]
+///     The values at which the derivative is evaluated.
+/// vectorized : bool, optional
+///     If True, `f` is called once with a numpy.ndarray of dual numbers
+///     with the shape of `x`. Otherwise, `f` is called for every value.
+///
+/// Returns
+/// -------
+/// function values and first derivatives as numpy.ndarray with the shape of `x`
+#[expect(clippy::type_complexity)]
+pub fn first_derivative_batch<'py>(
+    f: &Bound<'py, PyAny>,
+    x: &Bound<'py, PyAny>,
+    vectorized: bool,
+) -> PyResult<(Bound<'py, PyArrayDyn<f64>>, Bound<'py, PyArrayDyn<f64>>)> {
+    let py = f.py();
+    let x = super::extract_array(x, "x")?;
+    let (re, eps): (Vec<_>, Vec<_>) = if vectorized {
+        let duals = x
+            .iter()
+            .map(|&x| Py::new(py, PyDual64::from(Dual64::new(x, 1.0))))
+            .collect::<PyResult<Vec<_>>>()?;
+        let duals = ArrayD::from_shape_vec(x.raw_dim(), duals).unwrap();
+        let res = f.call1((PyArray::from_owned_object_array(py, duals),))?;
+        broadcast_result(&res, x.shape())?
+            .iter()
+            .map(|r| result_dual::<Dual64, PyDual64>(r).map(|r| (r.re, r.eps)))
+            .collect::<PyResult<Vec<_>>>()?
+    } else {
+        x.iter()
+            .map(|&x| first_derivative(f, x))
+            .collect::<PyResult<Vec<_>>>()?
+    }
+    .into_iter()
+    .unzip();
+    Ok((
+        ArrayD::from_shape_vec(x.raw_dim(), re)
+            .unwrap()
+            .into_pyarray(py),
+        ArrayD::from_shape_vec(x.raw_dim(), eps)
+            .unwrap()
+            .into_pyarray(py),
+    ))
+}
+
+/// Evaluate a vectorized scalar function once for all points (rows of `x`). The function is
+/// called with a list that contains a numpy.ndarray of dual numbers for every variable.
+fn gradient_vectorized<D: Dim, P: PyDualNumber<DualVec64<D>>>(
+    f: &Bound<'_, PyAny>,
+    x: &Array2<f64>,
+    n: D,
+) -> PyResult<(Vec<f64>, Vec<f64>)>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    let py = f.py();
+    let args = x
+        .columns()
+        .into_iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            let xi = xi
+                .iter()
+                .map(|&xi| {
+                    let eps = Derivative::derivative_generic(n, U1, i);
+                    Py::new(py, P::from(DualVec::new(xi, eps)))
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyArray::from_owned_object_array(py, Array1::from(xi)))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let res = f.call1((args,))?;
+    let mut re = Vec::with_capacity(x.nrows());
+    let mut grad = Vec::with_capacity(x.len());
+    for r in broadcast_result(&res, &[x.nrows()])? {
+        let r = result_dual::<DualVec64<D>, P>(&r)?;
+        re.push(r.re);
+        grad.extend(r.eps.unwrap_generic(n, U1).iter());
+    }
+    Ok((re, grad))
+}
+
 macro_rules! impl_gradient_and_jacobian {
-    ([$(($py_type_name:ident, $n:literal)),+]) => {
+    ([$(($py_type_name:ident, $py_name:literal, $n:literal)),+]) => {
         #[pyfunction]
         /// Calculate the gradient of a scalar, multivariate function.
         ///
@@ -115,19 +345,50 @@ macro_rules! impl_gradient_and_jacobian {
         /// ----------
         /// f : callable
         ///     A scalar, multivariate function.
-        /// x : [float]
+        /// x : numpy.ndarray or [float]
         ///     The vector for which the gradient is evaluated.
         ///
         /// Returns
         /// -------
-        /// function value and gradient
-        pub fn gradient(f: &Bound<'_, PyAny>, x: &Bound<'_, PyAny>) -> PyResult<(f64, Vec<f64>)> {
-            $(
-                if let Ok(x) = x.extract::<[f64; $n]>() {
-                    let g = |x: SVector<DualSVec64<$n>, $n>| {
-                        let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+        /// function value and gradient as numpy.ndarray
+        pub fn gradient<'py>(
+            f: &Bound<'py, PyAny>,
+            x: &Bound<'py, PyAny>,
+        ) -> PyResult<(f64, Bound<'py, PyArray1<f64>>)> {
+            let x = super::extract_vector(x, "x", "first_derivative")?;
+            let (re, grad) = gradient_point(f, &x)?;
+            Ok((re, PyArray1::from_vec(f.py(), grad)))
+        }
+
+        fn gradient_point(f: &Bound<'_, PyAny>, x: &[f64]) -> PyResult<(f64, Vec<f64>)> {
+            // Inputs with more variables than the largest static size are evaluated with dynamically
+            // sized dual numbers in a single call of `f`. Chunks of static dual numbers would require
+            // one call of `f` per chunk, which is slower for Python functions (see
+            // `benches/gradient.py`).
+            match x.len() {
+                $(
+                    $n => {
+                        let g = |x: SVector<DualSVec64<$n>, $n>| {
+                            let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+                            let res = f.call1((x,))?;
+                            if let Ok(res) = res.extract::<$py_type_name>() {
+                                Ok(res.0)
+                            } else {
+                                Err(PyErr::new::<PyTypeError, _>(
+                                    "argument 'f' must return a scalar. For vector functions use 'jacobian' instead."
+                                        .to_string(),
+                                ))
+                            }
+                        };
+                        crate::gradient(g, &SVector::from_column_slice(x))
+                            .map(|(re, eps)| (re, eps.as_slice().to_vec()))
+                    }
+                )+
+                _ => {
+                    let g = |x: DVector<DualDVec64>| {
+                        let x: Vec<_> = x.into_iter().map(|x| PyDual64Dyn::from(x.clone())).collect();
                         let res = f.call1((x,))?;
-                        if let Ok(res) = res.extract::<$py_type_name>() {
+                        if let Ok(res) = res.extract::<PyDual64Dyn>() {
                             Ok(res.0)
                         } else {
                             Err(PyErr::new::<PyTypeError, _>(
@@ -136,30 +397,57 @@ macro_rules! impl_gradient_and_jacobian {
                             ))
                         }
                     };
-                    crate::gradient(g, &SVector::from(x)).map(|(re, eps)| (re, eps.data.0[0].to_vec()))
-                } else
-            )+
-            if let Ok(x) = x.extract::<Vec<f64>>() {
-                let g = |x: DVector<DualDVec64>| {
-                    let x: Vec<_> = x.into_iter().map(|x| PyDual64Dyn::from(x.clone())).collect();
-                    let res = f.call1((x,))?;
-                    if let Ok(res) = res.extract::<PyDual64Dyn>() {
-                        Ok(res.0)
-                    } else {
-                        Err(PyErr::new::<PyTypeError, _>(
-                            "argument 'f' must return a scalar. For vector functions use 'jacobian' instead."
-                                .to_string(),
-                        ))
-                    }
-                };
-                crate::gradient(g, &DVector::from(x)).map(|(re, eps)| (re, eps.data.as_vec().clone()))
-            } else {
-                Err(PyErr::new::<PyTypeError, _>(
-                        "argument 'x': must be a list. For univariate functions use 'first_derivative' instead.".to_string(),
-                    ))
+                    crate::gradient(g, &DVector::from_column_slice(x)).map(|(re, eps)| (re, eps.as_slice().to_vec()))
+                }
             }
         }
 
+        #[pyfunction]
+        #[pyo3(signature = (f, x, vectorized=false))]
+        /// Calculate the gradient of a scalar, multivariate function at many points.
+        ///
+        /// Parameters
+        /// ----------
+        /// f : callable
+        ///     A scalar, multivariate function.
+        /// x : numpy.ndarray or [[float]]
+        ///     The points (one per row) at which the gradient is evaluated.
+        /// vectorized : bool, optional
+        ///     If True, `f` is called once with a list that contains a numpy.ndarray
+        ///     of dual numbers for every variable. Otherwise, `f` is called for every point.
+        ///
+        /// Returns
+        /// -------
+        /// function values and gradients (one per row) as numpy.ndarray
+        pub fn gradient_batch<'py>(
+            f: &Bound<'py, PyAny>,
+            x: &Bound<'py, PyAny>,
+            vectorized: bool,
+        ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray2<f64>>)> {
+            let py = f.py();
+            let x = super::extract_points(x, "x")?;
+            let (m, n) = x.dim();
+            let (re, grad) = if vectorized {
+                match n {
+                    $($n => gradient_vectorized::<Const<$n>, $py_type_name>(f, &x, Const),)+
+                    _ => gradient_vectorized::<Dyn, PyDual64Dyn>(f, &x, Dyn(n)),
+                }?
+            } else {
+                let mut re = Vec::with_capacity(m);
+                let mut grad = Vec::with_capacity(m * n);
+                for xi in x.rows() {
+                    let (r, g) = gradient_point(f, &xi.to_vec())?;
+                    re.push(r);
+                    grad.extend(g);
+                }
+                (re, grad)
+            };
+            Ok((
+                PyArray1::from_vec(py, re),
+                Array2::from_shape_vec((m, n), grad).unwrap().into_pyarray(py),
+            ))
+        }
+
         #[pyfunction]
         /// Calculate the Jacobian of a vector, multivariate function.
         ///
@@ -167,19 +455,44 @@ macro_rules! impl_gradient_and_jacobian {
         /// ----------
         /// f : callable
         ///     A vector, multivariate function.
-        /// x : [float]
+        /// x : numpy.ndarray or [float]
         ///     The vector for which the Jacobian is evaluated.
         ///
         /// Returns
         /// -------
-        /// function values and Jacobian
-        pub fn jacobian(f: &Bound<'_, PyAny>, x: &Bound<'_, PyAny>) -> PyResult<(Vec<f64>, Vec<Vec<f64>>)> {
-            $(
-                if let Ok(x) = x.extract::<[f64; $n]>() {
-                    let g = |x: SVector<DualSVec64<$n>, $n>| {
-                        let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+        /// function values and Jacobian as numpy.ndarray
+        pub fn jacobian<'py>(
+            f: &Bound<'py, PyAny>,
+            x: &Bound<'py, PyAny>,
+        ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray2<f64>>)> {
+            let py = f.py();
+            let x = super::extract_vector(x, "x", "first_derivative")?;
+            match x.len() {
+                $(
+                    $n => {
+                        let g = |x: SVector<DualSVec64<$n>, $n>| {
+                            let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+                            let res = f.call1((x,))?;
+                            if let Ok(res) = res.extract::<Vec<$py_type_name>>() {
+                                let res = DVector::from_iterator(res.len(), res.into_iter().map(|r| r.0));
+                                Ok(res)
+                            } else {
+                                Err(PyErr::new::<PyTypeError, _>(
+                                    "argument 'f' must return a list. For scalar functions use 'first_derivative' or 'gradient' instead."
+                                        .to_string(),
+                                ))
+                            }
+                        };
+                        crate::jacobian(g, &SVector::from_column_slice(&x)).map(|(re, eps)| {
+                            (PyArray1::from_slice(py, re.as_slice()), super::matrix_to_pyarray(py, &eps))
+                        })
+                    }
+                )+
+                _ => {
+                    let g = |x: DVector<DualDVec64>| {
+                        let x: Vec<_> = x.into_iter().map(|x| PyDual64Dyn::from(x.clone())).collect();
                         let res = f.call1((x,))?;
-                        if let Ok(res) = res.extract::<Vec<$py_type_name>>() {
+                        if let Ok(res) = res.extract::<Vec<PyDual64Dyn>>() {
                             let res = DVector::from_iterator(res.len(), res.into_iter().map(|r| r.0));
                             Ok(res)
                         } else {
@@ -189,62 +502,32 @@ macro_rules! impl_gradient_and_jacobian {
                             ))
                         }
                     };
-                    crate::jacobian(g, &SVector::from(x)).map(|(re, eps)| {
-                        let eps: Vec<_> = eps
-                            .row_iter()
-                            .map(|r| r.iter().copied().collect::<Vec<_>>())
-                            .collect();
-                        (re.iter().copied().collect(), eps)
+                    crate::jacobian(g, &DVector::from(x)).map(|(re, eps)| {
+                        (PyArray1::from_slice(py, re.as_slice()), super::matrix_to_pyarray(py, &eps))
                     })
-                } else
-            )+
-            if let Ok(x) = x.extract::<Vec<f64>>() {
-                let g = |x: DVector<DualDVec64>| {
-                    let x: Vec<_> = x.into_iter().map(|x| PyDual64Dyn::from(x.clone())).collect();
-                    let res = f.call1((x,))?;
-                    if let Ok(res) = res.extract::<Vec<PyDual64Dyn>>() {
-                        let res = DVector::from_iterator(res.len(), res.into_iter().map(|r| r.0));
-                        Ok(res)
-                    } else {
-                        Err(PyErr::new::<PyTypeError, _>(
-                            "argument 'f' must return a list. For scalar functions use 'first_derivative' or 'gradient' instead."
-                                .to_string(),
-                        ))
-                    }
-                };
-                crate::jacobian(g, &DVector::from(x)).map(|(re, eps)| {
-                    let eps: Vec<_> = eps
-                        .row_iter()
-                        .map(|r| r.iter().copied().collect::<Vec<_>>())
-                        .collect();
-                    (re.iter().copied().collect(), eps)
-                })
-            } else {
-                Err(PyErr::new::<PyTypeError, _>(
-                        "argument 'x': must be a list. For univariate functions use 'first_derivative' instead.".to_string(),
-                    ))
+                }
             }
         }
 
-        $(impl_dual_n!($py_type_name, $n);)+
+        $(impl_dual_n!($py_type_name, $py_name, $n);)+
     };
 }
 
 impl_gradient_and_jacobian!([
-    (PyDual64_1, 1),
-    (PyDual64_2, 2),
-    (PyDual64_3, 3),
-    (PyDual64_4, 4),
-    (PyDual64_5, 5),
-    (PyDual64_6, 6),
-    (PyDual64_7, 7),
-    (PyDual64_8, 8),
-    (PyDual64_9, 9),
-    (PyDual64_10, 10),
-    (PyDual64_11, 11),
-    (PyDual64_12, 12),
-    (PyDual64_13, 13),
-    (PyDual64_14, 14),
-    (PyDual64_15, 15),
-    (PyDual64_16, 16)
+    (PyDual64_1, "DualSVec64_1", 1),
+    (PyDual64_2, "DualSVec64_2", 2),
+    (PyDual64_3, "DualSVec64_3", 3),
+    (PyDual64_4, "DualSVec64_4", 4),
+    (PyDual64_5, "DualSVec64_5", 5),
+    (PyDual64_6, "DualSVec64_6", 6),
+    (PyDual64_7, "DualSVec64_7", 7),
+    (PyDual64_8, "DualSVec64_8", 8),
+    (PyDual64_9, "DualSVec64_9", 9),
+    (PyDual64_10, "DualSVec64_10", 10),
+    (PyDual64_11, "DualSVec64_11", 11),
+    (PyDual64_12, "DualSVec64_12", 12),
+    (PyDual64_13, "DualSVec64_13", 13),
+    (PyDual64_14, "DualSVec64_14", 14),
+    (PyDual64_15, "DualSVec64_15", 15),
+    (PyDual64_16, "DualSVec64_16", 16)
 ]);
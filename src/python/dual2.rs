@@ -1,11 +1,13 @@
-use super::dual::PyDual64;
+use super::dual::{PyDual64, PyDual64Dyn};
+use super::dual3::PyDual3_64;
+use super::hyperdual::{PyHyperDual64, PyHyperDual64Dyn};
 use crate::*;
-use nalgebra::{DVector, SVector};
-use numpy::{PyArray, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
-use pyo3::exceptions::PyTypeError;
+use nalgebra::{DMatrix, DVector, RowDVector, SVector};
+use numpy::{PyArray, PyArray1, PyArray2, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
-#[pyclass(name = "Dual2_64")]
+#[pyclass(module = "num_dual", name = "Dual2_64")]
 #[derive(Clone)]
 /// Second order dual number using 64-bit-floats as fields.
 ///
@@ -55,34 +57,103 @@ impl PyDual2_64 {
 
 impl_dual_num!(PyDual2_64, Dual2_64, f64);
 
-#[pyclass(name = "Dual2Dual64")]
-#[derive(Clone)]
-/// Second order dual number using dual numbers as fields.
-pub struct PyDual2Dual64(Dual2<Dual64, f64>);
+macro_rules! impl_dual2 {
+    ($py_type_name:ident, $py_name:literal, $doc:literal, $field_type:ty, $inner:ty, $float:ty) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
+        #[derive(Clone)]
+        #[doc = $doc]
+        pub struct $py_type_name(Dual2<$inner, $float>);
 
-#[pymethods]
-impl PyDual2Dual64 {
-    #[new]
-    pub fn new(v0: PyDual64, v1: PyDual64, v2: PyDual64) -> Self {
-        Dual2::new(v0.into(), v1.into(), v2.into()).into()
-    }
+        #[pymethods]
+        impl $py_type_name {
+            #[new]
+            fn new(v0: $field_type, v1: $field_type, v2: $field_type) -> Self {
+                Dual2::new(v0.into(), v1.into(), v2.into()).into()
+            }
 
-    #[getter]
-    fn get_first_derivative(&self) -> PyDual64 {
-        self.0.v1.into()
-    }
+            #[getter]
+            fn get_first_derivative(&self) -> $field_type {
+                self.0.v1.clone().into()
+            }
 
-    #[getter]
-    fn get_second_derivative(&self) -> PyDual64 {
-        self.0.v2.into()
-    }
+            #[getter]
+            fn get_second_derivative(&self) -> $field_type {
+                self.0.v2.clone().into()
+            }
+        }
+
+        impl_dual_num!($py_type_name, Dual2<$inner, $float>, $field_type, $float);
+    };
 }
 
-impl_dual_num!(PyDual2Dual64, Dual2<Dual64, f64>, PyDual64);
+impl_dual2!(
+    PyDual2_32,
+    "Dual2_32",
+    "Second order dual number using 32-bit-floats as fields.",
+    f32,
+    f32,
+    f32
+);
+impl_dual2!(
+    PyDual2Dual64,
+    "Dual2Dual64",
+    "Second order dual number using dual numbers as fields.",
+    PyDual64,
+    Dual64,
+    f64
+);
+impl_dual2!(
+    PyDual2Dual2_64,
+    "Dual2Dual2_64",
+    "Second order dual number using second order dual numbers as fields.",
+    PyDual2_64,
+    Dual2_64,
+    f64
+);
+impl_dual2!(
+    PyDual2Dual3_64,
+    "Dual2Dual3_64",
+    "Second order dual number using third order dual numbers as fields.",
+    PyDual3_64,
+    Dual3_64,
+    f64
+);
+impl_dual2!(
+    PyDual2HyperDual64,
+    "Dual2HyperDual64",
+    "Second order dual number using hyper-dual numbers as fields.",
+    PyHyperDual64,
+    HyperDual64,
+    f64
+);
+impl_dual2!(
+    PyDual2Dual64Dyn,
+    "Dual2Dual64Dyn",
+    "Second order dual number using dual numbers with a dynamic number of derivatives as fields.",
+    PyDual64Dyn,
+    DualDVec64,
+    f64
+);
+impl_dual2!(
+    PyDual2Dual2_64Dyn,
+    "Dual2Dual2_64Dyn",
+    "Second order dual number using second order dual numbers with a dynamic number of variables as fields.",
+    PyDual2_64Dyn,
+    Dual2DVec64,
+    f64
+);
+impl_dual2!(
+    PyDual2HyperDual64Dyn,
+    "Dual2HyperDual64Dyn",
+    "Second order dual number using hyper-dual numbers with a dynamic number of variables as fields.",
+    PyHyperDual64Dyn,
+    HyperDualDVec64,
+    f64
+);
 
 macro_rules! impl_dual2_n {
-    ($py_type_name:ident, $n:literal) => {
-        #[pyclass(name = "Dual2Vec64")]
+    ($py_type_name:ident, $py_name:literal, $n:literal) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
         #[derive(Clone, Copy)]
         pub struct $py_type_name(Dual2SVec64<$n>);
 
@@ -103,10 +174,41 @@ macro_rules! impl_dual2_n {
     };
 }
 
-#[pyclass(name = "Dual2_64Dyn")]
+#[pyclass(module = "num_dual", name = "Dual2_64Dyn")]
 #[derive(Clone)]
+/// Second order dual number with a dynamic number of variables and 64-bit-floats as fields.
 pub struct PyDual2_64Dyn(Dual2DVec64);
 
+#[pymethods]
+impl PyDual2_64Dyn {
+    #[new]
+    fn new(re: f64, v1: Vec<f64>, v2: Vec<Vec<f64>>) -> PyResult<Self> {
+        let n = v1.len();
+        if v2.len() != n || v2.iter().any(|r| r.len() != n) {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "argument 'v2' must be a {n}x{n} matrix."
+            )));
+        }
+        let v1 = RowDVector::from_vec(v1);
+        let v2 = DMatrix::from_row_iterator(n, n, v2.into_iter().flatten());
+        Ok(Dual2DVec64::new(re, Derivative::some(v1), Derivative::some(v2)).into())
+    }
+
+    #[getter]
+    fn get_first_derivative(&self) -> Option<Vec<f64>> {
+        self.0.v1.0.as_ref().map(|v1| v1.iter().copied().collect())
+    }
+
+    #[getter]
+    fn get_second_derivative(&self) -> Option<Vec<Vec<f64>>> {
+        self.0
+            .v2
+            .0
+            .as_ref()
+            .map(|v2| v2.row_iter().map(|r| r.iter().copied().collect()).collect())
+    }
+}
+
 impl_dual_num!(PyDual2_64Dyn, Dual2DVec64, f64);
 
 #[pyfunction]
@@ -137,7 +239,7 @@ pub fn second_derivative(f: &Bound<'_, PyAny>, x: f64) -> PyResult<(f64, f64, f6
 }
 
 macro_rules! impl_hessian {
-    ([$(($py_type_name:ident, $n:literal)),+]) => {
+    ([$(($py_type_name:ident, $py_name:literal, $n:literal)),+]) => {
         #[pyfunction]
         /// Calculate the Hessian of a scalar, multivariate function.
         ///
@@ -145,19 +247,43 @@ macro_rules! impl_hessian {
         /// ----------
         /// f : callable
         ///     A scalar, multivariate function.
-        /// x : [float]
+        /// x : numpy.ndarray or [float]
         ///     The vector for which the Hessian is evaluated.
         ///
         /// Returns
         /// -------
-        /// function value, gradient and Hessian
-        pub fn hessian(f: &Bound<'_, PyAny>, x: &Bound<'_, PyAny>) -> PyResult<(f64, Vec<f64>, Vec<Vec<f64>>)> {
-            $(
-                if let Ok(x) = x.extract::<[f64; $n]>() {
-                    let g = |x: SVector<Dual2SVec64<$n>, $n>| {
-                        let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+        /// function value, gradient and Hessian as numpy.ndarray
+        pub fn hessian<'py>(
+            f: &Bound<'py, PyAny>,
+            x: &Bound<'py, PyAny>,
+        ) -> PyResult<(f64, Bound<'py, PyArray1<f64>>, Bound<'py, PyArray2<f64>>)> {
+            let py = f.py();
+            let x = super::extract_vector(x, "x", "second_derivative")?;
+            match x.len() {
+                $(
+                    $n => {
+                        let g = |x: SVector<Dual2SVec64<$n>, $n>| {
+                            let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+                            let res = f.call1((x,))?;
+                            if let Ok(res) = res.extract::<$py_type_name>() {
+                                Ok(res.0)
+                            } else {
+                                Err(PyErr::new::<PyTypeError, _>(
+                                    "argument 'f' must return a scalar."
+                                        .to_string(),
+                                ))
+                            }
+                        };
+                        crate::hessian(g, &SVector::from_column_slice(&x)).map(|(f, g, h)| {
+                            (f, PyArray1::from_slice(py, g.as_slice()), super::matrix_to_pyarray(py, &h))
+                        })
+                    }
+                )+
+                _ => {
+                    let g = |x: DVector<Dual2DVec64>| {
+                        let x: Vec<_> = x.into_iter().map(|x| PyDual2_64Dyn::from(x.clone())).collect();
                         let res = f.call1((x,))?;
-                        if let Ok(res) = res.extract::<$py_type_name>() {
+                        if let Ok(res) = res.extract::<PyDual2_64Dyn>() {
                             Ok(res.0)
                         } else {
                             Err(PyErr::new::<PyTypeError, _>(
@@ -166,55 +292,32 @@ macro_rules! impl_hessian {
                             ))
                         }
                     };
-                    crate::hessian(g, &SVector::from(x)).map(|(f, g, h)| {
-                        let h = h.row_iter().map(|r| r.iter().copied().collect()).collect();
-                        (f, g.data.0[0].to_vec(), h)
+                    crate::hessian(g, &DVector::from(x)).map(|(f, g, h)| {
+                        (f, PyArray1::from_slice(py, g.as_slice()), super::matrix_to_pyarray(py, &h))
                     })
-                } else
-            )+
-            if let Ok(x) = x.extract::<Vec<f64>>() {
-                let g = |x: DVector<Dual2DVec64>| {
-                    let x: Vec<_> = x.into_iter().map(|x| PyDual2_64Dyn::from(x.clone())).collect();
-                    let res = f.call1((x,))?;
-                    if let Ok(res) = res.extract::<PyDual2_64Dyn>() {
-                        Ok(res.0)
-                    } else {
-                        Err(PyErr::new::<PyTypeError, _>(
-                            "argument 'f' must return a scalar."
-                                .to_string(),
-                        ))
-                    }
-                };
-                crate::hessian(g, &DVector::from(x)).map(|(f, g, h)| {
-                    let h = h.row_iter().map(|r| r.iter().copied().collect()).collect();
-                    (f, g.data.as_vec().clone(), h)
-                })
-            } else {
-                Err(PyErr::new::<PyTypeError, _>(
-                        "argument 'x': must be a list. For univariate functions use 'second_derivative' instead.".to_string(),
-                    ))
+                }
             }
         }
 
-        $(impl_dual2_n!($py_type_name, $n);)+
+        $(impl_dual2_n!($py_type_name, $py_name, $n);)+
     };
 }
 
 impl_hessian!([
-    (PyDual2_64_1, 1),
-    (PyDual2_64_2, 2),
-    (PyDual2_64_3, 3),
-    (PyDual2_64_4, 4),
-    (PyDual2_64_5, 5),
-    (PyDual2_64_6, 6),
-    (PyDual2_64_7, 7),
-    (PyDual2_64_8, 8),
-    (PyDual2_64_9, 9),
-    (PyDual2_64_10, 10),
-    (PyDual2_64_11, 11),
-    (PyDual2_64_12, 12),
-    (PyDual2_64_13, 13),
-    (PyDual2_64_14, 14),
-    (PyDual2_64_15, 15),
-    (PyDual2_64_16, 16)
+    (PyDual2_64_1, "Dual2SVec64_1", 1),
+    (PyDual2_64_2, "Dual2SVec64_2", 2),
+    (PyDual2_64_3, "Dual2SVec64_3", 3),
+    (PyDual2_64_4, "Dual2SVec64_4", 4),
+    (PyDual2_64_5, "Dual2SVec64_5", 5),
+    (PyDual2_64_6, "Dual2SVec64_6", 6),
+    (PyDual2_64_7, "Dual2SVec64_7", 7),
+    (PyDual2_64_8, "Dual2SVec64_8", 8),
+    (PyDual2_64_9, "Dual2SVec64_9", 9),
+    (PyDual2_64_10, "Dual2SVec64_10", 10),
+    (PyDual2_64_11, "Dual2SVec64_11", 11),
+    (PyDual2_64_12, "Dual2SVec64_12", 12),
+    (PyDual2_64_13, "Dual2SVec64_13", 13),
+    (PyDual2_64_14, "Dual2SVec64_14", 14),
+    (PyDual2_64_15, "Dual2SVec64_15", 15),
+    (PyDual2_64_16, "Dual2SVec64_16", 16)
 ]);
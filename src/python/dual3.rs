@@ -1,10 +1,12 @@
-use super::dual::PyDual64;
+use super::dual::{PyDual64, PyDual64Dyn};
+use super::dual2::{PyDual2_64, PyDual2_64Dyn};
+use super::hyperdual::{PyHyperDual64, PyHyperDual64Dyn};
 use crate::*;
 use numpy::{PyArray, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 
-#[pyclass(name = "Dual3_64")]
+#[pyclass(module = "num_dual", name = "Dual3_64")]
 #[derive(Clone)]
 /// Third order dual number using 64-bit-floats as fields.
 pub struct PyDual3_64(Dual3_64);
@@ -34,35 +36,104 @@ impl PyDual3_64 {
 
 impl_dual_num!(PyDual3_64, Dual3_64, f64);
 
-#[pyclass(name = "Dual3Dual64")]
-#[derive(Clone)]
-/// Third order dual number using dual numbers as fields.
-pub struct PyDual3Dual64(Dual3<Dual64, f64>);
+macro_rules! impl_dual3 {
+    ($py_type_name:ident, $py_name:literal, $doc:literal, $field_type:ty, $inner:ty, $float:ty) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
+        #[derive(Clone)]
+        #[doc = $doc]
+        pub struct $py_type_name(Dual3<$inner, $float>);
 
-#[pymethods]
-impl PyDual3Dual64 {
-    #[new]
-    pub fn new(v0: PyDual64, v1: PyDual64, v2: PyDual64, v3: PyDual64) -> Self {
-        Dual3::new(v0.into(), v1.into(), v2.into(), v3.into()).into()
-    }
+        #[pymethods]
+        impl $py_type_name {
+            #[new]
+            fn new(v0: $field_type, v1: $field_type, v2: $field_type, v3: $field_type) -> Self {
+                Dual3::new(v0.into(), v1.into(), v2.into(), v3.into()).into()
+            }
 
-    #[getter]
-    fn get_first_derivative(&self) -> PyDual64 {
-        self.0.v1.into()
-    }
+            #[getter]
+            fn get_first_derivative(&self) -> $field_type {
+                self.0.v1.clone().into()
+            }
 
-    #[getter]
-    fn get_second_derivative(&self) -> PyDual64 {
-        self.0.v2.into()
-    }
+            #[getter]
+            fn get_second_derivative(&self) -> $field_type {
+                self.0.v2.clone().into()
+            }
 
-    #[getter]
-    fn get_third_derivative(&self) -> PyDual64 {
-        self.0.v3.into()
-    }
+            #[getter]
+            fn get_third_derivative(&self) -> $field_type {
+                self.0.v3.clone().into()
+            }
+        }
+
+        impl_dual_num!($py_type_name, Dual3<$inner, $float>, $field_type, $float);
+    };
 }
 
-impl_dual_num!(PyDual3Dual64, Dual3<Dual64, f64>, PyDual64);
+impl_dual3!(
+    PyDual3_32,
+    "Dual3_32",
+    "Third order dual number using 32-bit-floats as fields.",
+    f32,
+    f32,
+    f32
+);
+impl_dual3!(
+    PyDual3Dual64,
+    "Dual3Dual64",
+    "Third order dual number using dual numbers as fields.",
+    PyDual64,
+    Dual64,
+    f64
+);
+impl_dual3!(
+    PyDual3Dual2_64,
+    "Dual3Dual2_64",
+    "Third order dual number using second order dual numbers as fields.",
+    PyDual2_64,
+    Dual2_64,
+    f64
+);
+impl_dual3!(
+    PyDual3Dual3_64,
+    "Dual3Dual3_64",
+    "Third order dual number using third order dual numbers as fields.",
+    PyDual3_64,
+    Dual3_64,
+    f64
+);
+impl_dual3!(
+    PyDual3HyperDual64,
+    "Dual3HyperDual64",
+    "Third order dual number using hyper-dual numbers as fields.",
+    PyHyperDual64,
+    HyperDual64,
+    f64
+);
+impl_dual3!(
+    PyDual3Dual64Dyn,
+    "Dual3Dual64Dyn",
+    "Third order dual number using dual numbers with a dynamic number of derivatives as fields.",
+    PyDual64Dyn,
+    DualDVec64,
+    f64
+);
+impl_dual3!(
+    PyDual3Dual2_64Dyn,
+    "Dual3Dual2_64Dyn",
+    "Third order dual number using second order dual numbers with a dynamic number of variables as fields.",
+    PyDual2_64Dyn,
+    Dual2DVec64,
+    f64
+);
+impl_dual3!(
+    PyDual3HyperDual64Dyn,
+    "Dual3HyperDual64Dyn",
+    "Third order dual number using hyper-dual numbers with a dynamic number of variables as fields.",
+    PyHyperDual64Dyn,
+    HyperDualDVec64,
+    f64
+);
 
 #[pyfunction]
 /// Calculate the third derivative of a scalar, univariate function.
@@ -1,11 +1,13 @@
-use super::dual::PyDual64;
+use super::dual::{PyDual64, PyDual64Dyn};
+use super::dual2::{PyDual2_64, PyDual2_64Dyn};
+use super::dual3::PyDual3_64;
 use crate::*;
-use nalgebra::{DVector, SVector};
-use numpy::{PyArray, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
-use pyo3::exceptions::PyTypeError;
+use nalgebra::{DMatrix, DVector, RowDVector, SVector};
+use numpy::{PyArray, PyArray1, PyArray2, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
-#[pyclass(name = "HyperDual64")]
+#[pyclass(module = "num_dual", name = "HyperDual64")]
 #[derive(Clone)]
 /// Hyper-dual number using 64-bit-floats as fields.
 ///
@@ -54,34 +56,108 @@ impl PyHyperDual64 {
 
 impl_dual_num!(PyHyperDual64, HyperDual64, f64);
 
-#[pyclass(name = "HyperDualDual64")]
-#[derive(Clone)]
-/// Hyper-dual number using dual numbers as fields.
-pub struct PyHyperDualDual64(HyperDual<Dual64, f64>);
+macro_rules! impl_hyper_dual {
+    ($py_type_name:ident, $py_name:literal, $doc:literal, $field_type:ty, $inner:ty, $float:ty) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
+        #[derive(Clone)]
+        #[doc = $doc]
+        pub struct $py_type_name(HyperDual<$inner, $float>);
 
-#[pymethods]
-impl PyHyperDualDual64 {
-    #[new]
-    pub fn new(re: PyDual64, eps1: PyDual64, eps2: PyDual64, eps1eps2: PyDual64) -> Self {
-        HyperDual::new(re.into(), eps1.into(), eps2.into(), eps1eps2.into()).into()
-    }
+        #[pymethods]
+        impl $py_type_name {
+            #[new]
+            fn new(
+                re: $field_type,
+                eps1: $field_type,
+                eps2: $field_type,
+                eps1eps2: $field_type,
+            ) -> Self {
+                HyperDual::new(re.into(), eps1.into(), eps2.into(), eps1eps2.into()).into()
+            }
 
-    #[getter]
-    fn get_first_derivative(&self) -> (PyDual64, PyDual64) {
-        (self.0.eps1.into(), self.0.eps2.into())
-    }
+            #[getter]
+            fn get_first_derivative(&self) -> ($field_type, $field_type) {
+                (self.0.eps1.clone().into(), self.0.eps2.clone().into())
+            }
 
-    #[getter]
-    fn get_second_derivative(&self) -> PyDual64 {
-        self.0.eps1eps2.into()
-    }
+            #[getter]
+            fn get_second_derivative(&self) -> $field_type {
+                self.0.eps1eps2.clone().into()
+            }
+        }
+
+        impl_dual_num!($py_type_name, HyperDual<$inner, $float>, $field_type, $float);
+    };
 }
 
-impl_dual_num!(PyHyperDualDual64, HyperDual<Dual64, f64>, PyDual64);
+impl_hyper_dual!(
+    PyHyperDual32,
+    "HyperDual32",
+    "Hyper-dual number using 32-bit-floats as fields.",
+    f32,
+    f32,
+    f32
+);
+impl_hyper_dual!(
+    PyHyperDualDual64,
+    "HyperDualDual64",
+    "Hyper-dual number using dual numbers as fields.",
+    PyDual64,
+    Dual64,
+    f64
+);
+impl_hyper_dual!(
+    PyHyperDualDual2_64,
+    "HyperDualDual2_64",
+    "Hyper-dual number using second order dual numbers as fields.",
+    PyDual2_64,
+    Dual2_64,
+    f64
+);
+impl_hyper_dual!(
+    PyHyperDualDual3_64,
+    "HyperDualDual3_64",
+    "Hyper-dual number using third order dual numbers as fields.",
+    PyDual3_64,
+    Dual3_64,
+    f64
+);
+impl_hyper_dual!(
+    PyHyperDualHyperDual64,
+    "HyperDualHyperDual64",
+    "Hyper-dual number using hyper-dual numbers as fields.",
+    PyHyperDual64,
+    HyperDual64,
+    f64
+);
+impl_hyper_dual!(
+    PyHyperDualDual64Dyn,
+    "HyperDualDual64Dyn",
+    "Hyper-dual number using dual numbers with a dynamic number of derivatives as fields.",
+    PyDual64Dyn,
+    DualDVec64,
+    f64
+);
+impl_hyper_dual!(
+    PyHyperDualDual2_64Dyn,
+    "HyperDualDual2_64Dyn",
+    "Hyper-dual number using second order dual numbers with a dynamic number of variables as fields.",
+    PyDual2_64Dyn,
+    Dual2DVec64,
+    f64
+);
+impl_hyper_dual!(
+    PyHyperDualHyperDual64Dyn,
+    "HyperDualHyperDual64Dyn",
+    "Hyper-dual number using hyper-dual numbers with a dynamic number of variables as fields.",
+    PyHyperDual64Dyn,
+    HyperDualDVec64,
+    f64
+);
 
 macro_rules! impl_hyper_dual_mn {
-    ($py_type_name:ident, $m:literal, $n:literal) => {
-        #[pyclass(name = "HyperDualVec64")]
+    ($py_type_name:ident, $py_name:literal, $m:literal, $n:literal) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
         #[derive(Clone, Copy)]
         pub struct $py_type_name(HyperDualSVec64<$m, $n>);
 
@@ -104,10 +180,60 @@ macro_rules! impl_hyper_dual_mn {
         impl_dual_num!($py_type_name, HyperDualSVec64<$m, $n>, f64);
     };
 }
-#[pyclass(name = "HyperDual64Dyn")]
+#[pyclass(module = "num_dual", name = "HyperDual64Dyn")]
 #[derive(Clone)]
+/// Hyper-dual number with a dynamic number of variables and 64-bit-floats as fields.
 pub struct PyHyperDual64Dyn(HyperDualDVec64);
 
+#[pymethods]
+impl PyHyperDual64Dyn {
+    #[new]
+    fn new(re: f64, eps1: Vec<f64>, eps2: Vec<f64>, eps1eps2: Vec<Vec<f64>>) -> PyResult<Self> {
+        let (m, n) = (eps1.len(), eps2.len());
+        if eps1eps2.len() != m || eps1eps2.iter().any(|r| r.len() != n) {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "argument 'eps1eps2' must be a {m}x{n} matrix."
+            )));
+        }
+        let eps1 = DVector::from_vec(eps1);
+        let eps2 = RowDVector::from_vec(eps2);
+        let eps1eps2 = DMatrix::from_row_iterator(m, n, eps1eps2.into_iter().flatten());
+        Ok(HyperDualDVec64::new(
+            re,
+            Derivative::some(eps1),
+            Derivative::some(eps2),
+            Derivative::some(eps1eps2),
+        )
+        .into())
+    }
+
+    #[getter]
+    fn get_first_derivative(&self) -> (Option<Vec<f64>>, Option<Vec<f64>>) {
+        (
+            self.0
+                .eps1
+                .0
+                .as_ref()
+                .map(|eps1| eps1.iter().copied().collect()),
+            self.0
+                .eps2
+                .0
+                .as_ref()
+                .map(|eps2| eps2.iter().copied().collect()),
+        )
+    }
+
+    #[getter]
+    fn get_second_derivative(&self) -> Option<Vec<Vec<f64>>> {
+        self.0.eps1eps2.0.as_ref().map(|eps1eps2| {
+            eps1eps2
+                .row_iter()
+                .map(|r| r.iter().copied().collect())
+                .collect()
+        })
+    }
+}
+
 impl_dual_num!(PyHyperDual64Dyn, HyperDualDVec64, f64);
 
 #[pyfunction]
@@ -145,7 +271,7 @@ pub fn second_partial_derivative(
 }
 
 macro_rules! impl_partial_hessian {
-    ([$(($py_type_name:ident, $m:literal, $n:literal)),+]) => {
+    ([$(($py_type_name:ident, $py_name:literal, $m:literal, $n:literal)),+]) => {
         #[pyfunction]
         /// Calculate the Hessian of a scalar function w.r.t. a subset of its variables.
         ///
@@ -153,26 +279,59 @@ macro_rules! impl_partial_hessian {
         /// ----------
         /// f : callable
         ///     A scalar, multivariate function.
-        /// x : [float]
+        /// x : numpy.ndarray or [float]
         ///     The first vector for which the partial Hessian is evaluated.
-        /// y : [float]
+        /// y : numpy.ndarray or [float]
         ///     The second vector for which the partial Hessian is evaluated.
         ///
         /// Returns
         /// -------
-        /// function value, gradient w.r.t. x, gradient w.r.t. y, and partial Hessian
-        pub fn partial_hessian(
-            f: &Bound<'_, PyAny>,
-            x: &Bound<'_, PyAny>,
-            y: &Bound<'_, PyAny>,
-        ) -> PyResult<(f64, Vec<f64>, Vec<f64>, Vec<Vec<f64>>)> {
-            $(
-                if let (Ok(x), Ok(y)) = (x.extract::<[f64; $m]>(), y.extract::<[f64; $n]>()) {
-                    let g = |(x, y): (SVector<HyperDualSVec64<$m, $n>, $m>, SVector<HyperDualSVec64<$m, $n>, $n>)| {
-                        let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
-                        let y: Vec<_> = y.into_iter().map(|&y| $py_type_name::from(y)).collect();
+        /// function value, gradient w.r.t. x, gradient w.r.t. y, and partial Hessian as numpy.ndarray
+        pub fn partial_hessian<'py>(
+            f: &Bound<'py, PyAny>,
+            x: &Bound<'py, PyAny>,
+            y: &Bound<'py, PyAny>,
+        ) -> PyResult<(
+            f64,
+            Bound<'py, PyArray1<f64>>,
+            Bound<'py, PyArray1<f64>>,
+            Bound<'py, PyArray2<f64>>,
+        )> {
+            let py = f.py();
+            let x = super::extract_vector(x, "x", "second_partial_derivative")?;
+            let y = super::extract_vector(y, "y", "second_partial_derivative")?;
+            match (x.len(), y.len()) {
+                $(
+                    ($m, $n) => {
+                        let g = |(x, y): (SVector<HyperDualSVec64<$m, $n>, $m>, SVector<HyperDualSVec64<$m, $n>, $n>)| {
+                            let x: Vec<_> = x.into_iter().map(|&x| $py_type_name::from(x)).collect();
+                            let y: Vec<_> = y.into_iter().map(|&y| $py_type_name::from(y)).collect();
+                            let res = f.call1((x, y))?;
+                            if let Ok(res) = res.extract::<$py_type_name>() {
+                                Ok(res.0)
+                            } else {
+                                Err(PyErr::new::<PyTypeError, _>(
+                                    "argument 'f' must return a scalar.".to_string(),
+                                ))
+                            }
+                        };
+                        crate::partial_hessian(g, (&SVector::from_column_slice(&x), &SVector::from_column_slice(&y)))
+                            .map(|(f, f_x, f_y, f_xy)| {
+                                (
+                                    f,
+                                    PyArray1::from_slice(py, f_x.as_slice()),
+                                    PyArray1::from_slice(py, f_y.as_slice()),
+                                    super::matrix_to_pyarray(py, &f_xy),
+                                )
+                            })
+                    }
+                )+
+                _ => {
+                    let g = |(x, y): (DVector<HyperDualDVec64>, DVector<HyperDualDVec64>)| {
+                        let x: Vec<_> = x.into_iter().map(|x| PyHyperDual64Dyn::from(x.clone())).collect();
+                        let y: Vec<_> = y.into_iter().map(|y| PyHyperDual64Dyn::from(y.clone())).collect();
                         let res = f.call1((x, y))?;
-                        if let Ok(res) = res.extract::<$py_type_name>() {
+                        if let Ok(res) = res.extract::<PyHyperDual64Dyn>() {
                             Ok(res.0)
                         } else {
                             Err(PyErr::new::<PyTypeError, _>(
@@ -180,69 +339,45 @@ macro_rules! impl_partial_hessian {
                             ))
                         }
                     };
-                    crate::partial_hessian(g, (&SVector::from(x), &SVector::from(y))).map(|(f, f_x, f_y, f_xy)| {
-                        let f_xy = f_xy
-                            .row_iter()
-                            .map(|r| r.iter().copied().collect())
-                            .collect();
-                        (f, f_x.data.0[0].to_vec(), f_y.data.0[0].to_vec(), f_xy)
+                    crate::partial_hessian(g, (&DVector::from(x), &DVector::from(y))).map(|(f, f_x, f_y, f_xy)| {
+                        (
+                            f,
+                            PyArray1::from_slice(py, f_x.as_slice()),
+                            PyArray1::from_slice(py, f_y.as_slice()),
+                            super::matrix_to_pyarray(py, &f_xy),
+                        )
                     })
-                } else
-            )+
-            if let (Ok(x), Ok(y)) = (x.extract::<Vec<f64>>(), y.extract::<Vec<f64>>()) {
-                let g = |(x, y): (DVector<HyperDualDVec64>, DVector<HyperDualDVec64>)| {
-                    let x: Vec<_> = x.into_iter().map(|x| PyHyperDual64Dyn::from(x.clone())).collect();
-                    let y: Vec<_> = y.into_iter().map(|y| PyHyperDual64Dyn::from(y.clone())).collect();
-                    let res = f.call1((x, y))?;
-                    if let Ok(res) = res.extract::<PyHyperDual64Dyn>() {
-                        Ok(res.0)
-                    } else {
-                        Err(PyErr::new::<PyTypeError, _>(
-                            "argument 'f' must return a scalar.".to_string(),
-                        ))
-                    }
-                };
-                crate::partial_hessian(g, (&DVector::from(x), &DVector::from(y))).map(|(f, f_x, f_y, f_xy)| {
-                    let f_xy = f_xy
-                        .row_iter()
-                        .map(|r| r.iter().copied().collect())
-                        .collect();
-                    (f, f_x.data.as_vec().clone(), f_y.data.as_vec().clone(), f_xy)
-                })
-            } else {
-                Err(PyErr::new::<PyTypeError, _>(
-                        "argument 'x' and 'y' must be lists. For bivariate functions use 'second_partial_derivative' instead.".to_string(),
-                    ))
+                }
             }
         }
-        $(impl_hyper_dual_mn!($py_type_name, $m, $n);)+
+        $(impl_hyper_dual_mn!($py_type_name, $py_name, $m, $n);)+
     };
 }
 
 impl_partial_hessian!([
-    (PyHyperDual64_1_1, 1, 1),
-    (PyHyperDual64_1_2, 1, 2),
-    (PyHyperDual64_1_3, 1, 3),
-    (PyHyperDual64_1_4, 1, 4),
-    (PyHyperDual64_1_5, 1, 5),
-    (PyHyperDual64_2_1, 2, 1),
-    (PyHyperDual64_2_2, 2, 2),
-    (PyHyperDual64_2_3, 2, 3),
-    (PyHyperDual64_2_4, 2, 4),
-    (PyHyperDual64_2_5, 2, 5),
-    (PyHyperDual64_3_1, 3, 1),
-    (PyHyperDual64_3_2, 3, 2),
-    (PyHyperDual64_3_3, 3, 3),
-    (PyHyperDual64_3_4, 3, 4),
-    (PyHyperDual64_3_5, 3, 5),
-    (PyHyperDual64_4_1, 4, 1),
-    (PyHyperDual64_4_2, 4, 2),
-    (PyHyperDual64_4_3, 4, 3),
-    (PyHyperDual64_4_4, 4, 4),
-    (PyHyperDual64_4_5, 4, 5),
-    (PyHyperDual64_5_1, 5, 1),
-    (PyHyperDual64_5_2, 5, 2),
-    (PyHyperDual64_5_3, 5, 3),
-    (PyHyperDual64_5_4, 5, 4),
-    (PyHyperDual64_5_5, 5, 5)
+    (PyHyperDual64_1_1, "HyperDualSVec64_1_1", 1, 1),
+    (PyHyperDual64_1_2, "HyperDualSVec64_1_2", 1, 2),
+    (PyHyperDual64_1_3, "HyperDualSVec64_1_3", 1, 3),
+    (PyHyperDual64_1_4, "HyperDualSVec64_1_4", 1, 4),
+    (PyHyperDual64_1_5, "HyperDualSVec64_1_5", 1, 5),
+    (PyHyperDual64_2_1, "HyperDualSVec64_2_1", 2, 1),
+    (PyHyperDual64_2_2, "HyperDualSVec64_2_2", 2, 2),
+    (PyHyperDual64_2_3, "HyperDualSVec64_2_3", 2, 3),
+    (PyHyperDual64_2_4, "HyperDualSVec64_2_4", 2, 4),
+    (PyHyperDual64_2_5, "HyperDualSVec64_2_5", 2, 5),
+    (PyHyperDual64_3_1, "HyperDualSVec64_3_1", 3, 1),
+    (PyHyperDual64_3_2, "HyperDualSVec64_3_2", 3, 2),
+    (PyHyperDual64_3_3, "HyperDualSVec64_3_3", 3, 3),
+    (PyHyperDual64_3_4, "HyperDualSVec64_3_4", 3, 4),
+    (PyHyperDual64_3_5, "HyperDualSVec64_3_5", 3, 5),
+    (PyHyperDual64_4_1, "HyperDualSVec64_4_1", 4, 1),
+    (PyHyperDual64_4_2, "HyperDualSVec64_4_2", 4, 2),
+    (PyHyperDual64_4_3, "HyperDualSVec64_4_3", 4, 3),
+    (PyHyperDual64_4_4, "HyperDualSVec64_4_4", 4, 4),
+    (PyHyperDual64_4_5, "HyperDualSVec64_4_5", 4, 5),
+    (PyHyperDual64_5_1, "HyperDualSVec64_5_1", 5, 1),
+    (PyHyperDual64_5_2, "HyperDualSVec64_5_2", 5, 2),
+    (PyHyperDual64_5_3, "HyperDualSVec64_5_3", 5, 3),
+    (PyHyperDual64_5_4, "HyperDualSVec64_5_4", 5, 4),
+    (PyHyperDual64_5_5, "HyperDualSVec64_5_5", 5, 5)
 ]);
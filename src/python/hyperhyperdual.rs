@@ -3,55 +3,72 @@ use numpy::{PyArray, PyReadonlyArrayDyn, PyReadwriteArrayDyn};
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 
-#[pyclass(name = "HyperHyperDual64")]
-#[derive(Clone)]
-/// Third order hyper dual number using 64-bit-floats as fields.
-pub struct PyHyperHyperDual64(HyperHyperDual64);
+macro_rules! impl_hyper_hyper_dual {
+    ($py_type_name:ident, $py_name:literal, $doc:literal, $float:ty) => {
+        #[pyclass(module = "num_dual", name = $py_name)]
+        #[derive(Clone)]
+        #[doc = $doc]
+        pub struct $py_type_name(HyperHyperDual<$float>);
 
-#[pymethods]
-#[expect(clippy::too_many_arguments)]
-impl PyHyperHyperDual64 {
-    #[new]
-    fn new(
-        re: f64,
-        eps1: f64,
-        eps2: f64,
-        eps3: f64,
-        eps1eps2: f64,
-        eps1eps3: f64,
-        eps2eps3: f64,
-        eps1eps2eps3: f64,
-    ) -> Self {
-        HyperHyperDual::new(
-            re,
-            eps1,
-            eps2,
-            eps3,
-            eps1eps2,
-            eps1eps3,
-            eps2eps3,
-            eps1eps2eps3,
-        )
-        .into()
-    }
+        #[pymethods]
+        #[expect(clippy::too_many_arguments)]
+        impl $py_type_name {
+            #[new]
+            fn new(
+                re: $float,
+                eps1: $float,
+                eps2: $float,
+                eps3: $float,
+                eps1eps2: $float,
+                eps1eps3: $float,
+                eps2eps3: $float,
+                eps1eps2eps3: $float,
+            ) -> Self {
+                HyperHyperDual::new(
+                    re,
+                    eps1,
+                    eps2,
+                    eps3,
+                    eps1eps2,
+                    eps1eps3,
+                    eps2eps3,
+                    eps1eps2eps3,
+                )
+                .into()
+            }
 
-    #[getter]
-    fn get_first_derivative(&self) -> (f64, f64, f64) {
-        (self.0.eps1, self.0.eps2, self.0.eps3)
-    }
+            #[getter]
+            fn get_first_derivative(&self) -> ($float, $float, $float) {
+                (self.0.eps1, self.0.eps2, self.0.eps3)
+            }
 
-    #[getter]
-    fn get_second_derivative(&self) -> (f64, f64, f64) {
-        (self.0.eps1eps2, self.0.eps1eps3, self.0.eps2eps3)
-    }
+            #[getter]
+            fn get_second_derivative(&self) -> ($float, $float, $float) {
+                (self.0.eps1eps2, self.0.eps1eps3, self.0.eps2eps3)
+            }
 
-    #[getter]
-    fn get_third_derivative(&self) -> f64 {
-        self.0.eps1eps2eps3
-    }
+            #[getter]
+            fn get_third_derivative(&self) -> $float {
+                self.0.eps1eps2eps3
+            }
+        }
+
+        impl_dual_num!($py_type_name, HyperHyperDual<$float>, $float, $float);
+    };
 }
 
-impl_dual_num!(PyHyperHyperDual64, HyperHyperDual64, f64);
+impl_hyper_hyper_dual!(
+    PyHyperHyperDual64,
+    "HyperHyperDual64",
+    "Third order hyper dual number using 64-bit-floats as fields.",
+    f64
+);
+impl_hyper_hyper_dual!(
+    PyHyperHyperDual32,
+    "HyperHyperDual32",
+    "Third order hyper dual number using 32-bit-floats as fields.",
+    f32
+);
 
 #[pyfunction]
 /// Calculate the third partial derivative of a scalar, trivariate function.
@@ -0,0 +1,536 @@
+use super::PyDualNumber;
+use super::dual::{PyDual32, PyDual64, PyDual64Dyn, PyDualDual64};
+use super::dual2::{PyDual2_64, PyDual2_64Dyn, PyDual2Dual64};
+use super::dual3::{PyDual3_64, PyDual3Dual64};
+use super::hyperdual::{PyHyperDual32, PyHyperDual64, PyHyperDualDual64};
+use super::hyperhyperdual::{PyHyperHyperDual32, PyHyperHyperDual64};
+use crate::linalg::LU;
+use crate::*;
+use nalgebra::{DMatrix, DVector};
+use pyo3::PyClass;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyFloat, PyInt, PyList, PyTuple};
+
+/// Dual numbers `D` for which the derivatives of a scalar potential w.r.t. a real variable
+/// can be evaluated with a nested dual number.
+trait Nested<F>: DualNum<F> + Copy {
+    type Nested: DualNum<F> + Copy;
+    type PyNested: PyDualNumber<Self::Nested>;
+
+    /// Nested dual number with the value `self` and the derivative `t` w.r.t. the variable.
+    fn lift(self, t: F) -> Self::Nested;
+
+    /// Derivative of the nested dual number w.r.t. the variable.
+    fn derivative(n: Self::Nested) -> Self;
+}
+
+impl Nested<f64> for Dual64 {
+    type Nested = HyperDual64;
+    type PyNested = PyHyperDual64;
+
+    fn lift(self, t: f64) -> HyperDual64 {
+        HyperDual64::new(self.re, self.eps, t, 0.0)
+    }
+
+    fn derivative(n: HyperDual64) -> Self {
+        Dual64::new(n.eps2, n.eps1eps2)
+    }
+}
+
+impl Nested<f64> for Dual2_64 {
+    type Nested = Dual2<Dual64, f64>;
+    type PyNested = PyDual2Dual64;
+
+    fn lift(self, t: f64) -> Self::Nested {
+        Dual2::new(
+            Dual64::new(self.re, t),
+            Dual64::from(self.v1),
+            Dual64::from(self.v2),
+        )
+    }
+
+    fn derivative(n: Self::Nested) -> Self {
+        Dual2_64::new(n.re.eps, n.v1.eps, n.v2.eps)
+    }
+}
+
+impl Nested<f64> for Dual3_64 {
+    type Nested = Dual3<Dual64, f64>;
+    type PyNested = PyDual3Dual64;
+
+    fn lift(self, t: f64) -> Self::Nested {
+        Dual3::new(
+            Dual64::new(self.re, t),
+            Dual64::from(self.v1),
+            Dual64::from(self.v2),
+            Dual64::from(self.v3),
+        )
+    }
+
+    fn derivative(n: Self::Nested) -> Self {
+        Dual3_64::new(n.re.eps, n.v1.eps, n.v2.eps, n.v3.eps)
+    }
+}
+
+impl Nested<f64> for HyperDual64 {
+    type Nested = HyperDual<Dual64, f64>;
+    type PyNested = PyHyperDualDual64;
+
+    fn lift(self, t: f64) -> Self::Nested {
+        HyperDual::new(
+            Dual64::new(self.re, t),
+            Dual64::from(self.eps1),
+            Dual64::from(self.eps2),
+            Dual64::from(self.eps1eps2),
+        )
+    }
+
+    fn derivative(n: Self::Nested) -> Self {
+        HyperDual64::new(n.re.eps, n.eps1.eps, n.eps2.eps, n.eps1eps2.eps)
+    }
+}
+
+impl Nested<f64> for Dual<Dual64, f64> {
+    type Nested = HyperHyperDual64;
+    type PyNested = PyHyperHyperDual64;
+
+    fn lift(self, t: f64) -> HyperHyperDual64 {
+        HyperHyperDual64::new(
+            self.re.re,
+            self.re.eps,
+            self.eps.re,
+            t,
+            self.eps.eps,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn derivative(n: HyperHyperDual64) -> Self {
+        Dual::new(
+            Dual64::new(n.eps3, n.eps1eps3),
+            Dual64::new(n.eps2eps3, n.eps1eps2eps3),
+        )
+    }
+}
+
+impl Nested<f32> for Dual32 {
+    type Nested = HyperDual32;
+    type PyNested = PyHyperDual32;
+
+    fn lift(self, t: f32) -> HyperDual32 {
+        HyperDual32::new(self.re, self.eps, t, 0.0)
+    }
+
+    fn derivative(n: HyperDual32) -> Self {
+        Dual32::new(n.eps2, n.eps1eps2)
+    }
+}
+
+impl Nested<f32> for HyperDual32 {
+    type Nested = HyperHyperDual32;
+    type PyNested = PyHyperHyperDual32;
+
+    fn lift(self, t: f32) -> HyperHyperDual32 {
+        HyperHyperDual32::new(
+            self.re,
+            self.eps1,
+            self.eps2,
+            t,
+            self.eps1eps2,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn derivative(n: HyperHyperDual32) -> Self {
+        HyperDual32::new(n.eps3, n.eps1eps3, n.eps2eps3, n.eps1eps2eps3)
+    }
+}
+
+/// Replace all dual numbers of type `P` in the parameters (a dual number or a list, tuple,
+/// or dict that contains dual numbers) with the result of `f`.
+fn map_parameters<'py, P: PyClass + Clone>(
+    args: &Bound<'py, PyAny>,
+    f: &dyn Fn(P) -> PyResult<Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = args.py();
+    if let Ok(p) = args.cast::<P>() {
+        f(p.borrow().clone())
+    } else if let Ok(list) = args.cast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|a| map_parameters(&a, f))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any())
+    } else if let Ok(tuple) = args.cast::<PyTuple>() {
+        let items = tuple
+            .iter()
+            .map(|a| map_parameters(&a, f))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyTuple::new(py, items)?.into_any())
+    } else if let Ok(dict) = args.cast::<PyDict>() {
+        let res = PyDict::new(py);
+        for (k, v) in dict.iter() {
+            res.set_item(k, map_parameters(&v, f)?)?;
+        }
+        Ok(res.into_any())
+    } else {
+        Ok(args.clone())
+    }
+}
+
+/// Parameters with all dual numbers replaced by their real parts.
+fn real_parameters<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    args: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = args.py();
+    map_parameters::<P>(args, &|p| {
+        Ok(PyFloat::new(py, Into::<D>::into(p).re().to_f64().unwrap()).into_any())
+    })
+}
+
+/// Extract a residual of type `D` that is returned as a dual number of type `P` or as a float.
+fn residual<D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    res: &Bound<'_, PyAny>,
+) -> PyResult<D> {
+    if let Ok(r) = res.cast::<P>() {
+        Ok(r.borrow().clone().into())
+    } else if res.is_instance_of::<PyFloat>() || res.is_instance_of::<PyInt>() {
+        Ok(D::from(F::from_f64(res.extract::<f64>()?).unwrap()))
+    } else {
+        Err(PyErr::new::<PyTypeError, _>(
+            "argument 'g' must return dual numbers or floats.".to_string(),
+        ))
+    }
+}
+
+/// Extract a vector of residuals.
+fn residuals<D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    res: &Bound<'_, PyAny>,
+) -> PyResult<DVector<D>> {
+    let res = res.extract::<Vec<Bound<'_, PyAny>>>().map_err(|_| {
+        PyErr::new::<PyTypeError, _>("argument 'g' must return a list of residuals.".to_string())
+    })?;
+    let res = res
+        .iter()
+        .map(|r| residual::<D, P, F>(r))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(DVector::from(res))
+}
+
+/// Convert a vector of dual numbers into a list of Python objects.
+fn to_list<'py, D: Clone, P: PyDualNumber<D>>(
+    py: Python<'py>,
+    x: &DVector<D>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    x.iter()
+        .map(|x| Ok(Bound::new(py, P::from(x.clone()))?.into_any()))
+        .collect()
+}
+
+/// LU decomposition of a real matrix that is evaluated in double precision.
+fn lu<F: DualNumFloat + DualNum<F>>(a: DMatrix<f64>) -> PyResult<LU<F, F, nalgebra::Dyn>> {
+    LU::new(a.map(|a| F::from_f64(a).unwrap()))
+        .map_err(|e| PyErr::new::<PyValueError, _>(e.to_string()))
+}
+
+/// Solve `g(x, args) = 0` for dual numbers `x`, starting from the real solution `x`.
+///
+/// The real Jacobian is evaluated once and every order of the derivatives of the solution
+/// is determined by a single evaluation of `g` with dual numbers of type `P`.
+fn implicit<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat + DualNum<F>>(
+    g: impl Fn(Vec<Bound<'py, PyAny>>, &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>>,
+    x: Vec<f64>,
+    args: &Bound<'py, PyAny>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let py = args.py();
+    let n = x.len();
+    let args_re = real_parameters::<D, P, F>(args)?;
+    let (_, jac) = crate::jacobian(
+        |x: DVector<DualDVec64>| {
+            residuals::<_, PyDual64Dyn, _>(&g(to_list::<_, PyDual64Dyn>(py, &x)?, &args_re)?)
+        },
+        &DVector::from(x.clone()),
+    )?;
+    if jac.nrows() != n {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "argument 'g' must return {n} residuals, got {}.",
+            jac.nrows()
+        )));
+    }
+    let lu = lu::<F>(jac)?;
+    let mut x = DVector::from(x).map(|x| D::from(F::from_f64(x).unwrap()));
+    for _ in 0..D::NDERIV {
+        let f = residuals::<D, P, F>(&g(to_list::<D, P>(py, &x)?, args)?)?;
+        x -= lu.solve_dual(&f);
+    }
+    to_list::<D, P>(py, &x)
+}
+
+/// Find the dual stationary point of the scalar potential `g(x, args)`, starting from the real
+/// stationary point `x`.
+fn implicit_sp<'py, D: Nested<F>, P: PyDualNumber<D>, F: DualNumFloat + DualNum<F>>(
+    g: &Bound<'py, PyAny>,
+    x: Vec<f64>,
+    args: &Bound<'py, PyAny>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let py = args.py();
+    let n = x.len();
+    let args_re = real_parameters::<D, P, F>(args)?;
+    let (_, _, hess) = crate::hessian(
+        |x: DVector<Dual2DVec64>| {
+            let res = g.call1((to_list::<_, PyDual2_64Dyn>(py, &x)?, &args_re))?;
+            residual::<Dual2DVec64, PyDual2_64Dyn, _>(&res)
+        },
+        &DVector::from(x.clone()),
+    )?;
+    let lu = lu::<F>(hess)?;
+    let args_nested = map_parameters::<P>(args, &|p| {
+        Ok(Bound::new(py, D::PyNested::from(Into::<D>::into(p).lift(F::zero())))?.into_any())
+    })?;
+    let mut x = DVector::from(x).map(|x| D::from(F::from_f64(x).unwrap()));
+    for _ in 0..D::NDERIV {
+        let grad = (0..n)
+            .map(|i| {
+                let xi = DVector::from_fn(n, |j, _| {
+                    x[j].lift(if i == j { F::one() } else { F::zero() })
+                });
+                let res = g.call1((to_list::<_, D::PyNested>(py, &xi)?, &args_nested))?;
+                Ok(D::derivative(residual::<_, D::PyNested, F>(&res)?))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        x -= lu.solve_dual(&DVector::from(grad));
+    }
+    to_list::<D, P>(py, &x)
+}
+
+#[pyfunction]
+/// Calculate the derivatives of the solution of the implicit function
+///     g(x, args) = 0
+/// w.r.t. the parameters, given the real solution.
+///
+/// Parameters
+/// ----------
+/// g : callable
+///     The residual as a function of the scalar variable and the parameters.
+/// x : float
+///     The real solution, e.g., obtained with scipy.optimize.
+/// args : dual number or list, tuple, or dict containing dual numbers
+///     The parameters.
+///
+/// Returns
+/// -------
+/// the solution as dual number of the same type as the parameters
+pub fn implicit_derivative<'py>(
+    g: &Bound<'py, PyAny>,
+    x: f64,
+    args: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let g = |x: Vec<Bound<'py, PyAny>>, args: &Bound<'py, PyAny>| {
+        let res = g.call1((&x[0], args))?;
+        Ok(PyList::new(args.py(), [res])?.into_any())
+    };
+    let x: PyResult<Vec<_>> = dispatch!("args", args, implicit(g, vec![x], args));
+    Ok(x?.remove(0))
+}
+
+#[pyfunction]
+/// Calculate the derivatives of the solution of the binary implicit function
+///     g(x, y, args) = 0
+/// w.r.t. the parameters, given the real solution.
+///
+/// Parameters
+/// ----------
+/// g : callable
+///     The two residuals as a function of both variables and the parameters.
+/// x : float
+///     The first variable of the real solution.
+/// y : float
+///     The second variable of the real solution.
+/// args : dual number or list, tuple, or dict containing dual numbers
+///     The parameters.
+///
+/// Returns
+/// -------
+/// both variables of the solution as dual numbers of the same type as the parameters
+pub fn implicit_derivative_binary<'py>(
+    g: &Bound<'py, PyAny>,
+    x: f64,
+    y: f64,
+    args: &Bound<'py, PyAny>,
+) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let g = |x: Vec<Bound<'py, PyAny>>, args: &Bound<'py, PyAny>| g.call1((&x[0], &x[1], args));
+    let x: PyResult<Vec<_>> = dispatch!("args", args, implicit(g, vec![x, y], args));
+    let [x, y]: [_; 2] = x?.try_into().unwrap();
+    Ok((x, y))
+}
+
+#[pyfunction]
+/// Calculate the derivatives of the solution of the multivariate implicit function
+///     g(x, args) = 0
+/// w.r.t. the parameters, given the real solution.
+///
+/// Parameters
+/// ----------
+/// g : callable
+///     The residuals as a function of the list of variables and the parameters.
+/// x : numpy.ndarray or [float]
+///     The real solution, e.g., obtained with scipy.optimize.
+/// args : dual number or list, tuple, or dict containing dual numbers
+///     The parameters.
+///
+/// Returns
+/// -------
+/// the solution as list of dual numbers of the same type as the parameters
+pub fn implicit_derivative_vec<'py>(
+    g: &Bound<'py, PyAny>,
+    x: &Bound<'py, PyAny>,
+    args: &Bound<'py, PyAny>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let x = super::extract_vector(x, "x", "implicit_derivative")?;
+    let g = |x: Vec<Bound<'py, PyAny>>, args: &Bound<'py, PyAny>| g.call1((x, args));
+    dispatch!("args", args, implicit(g, x, args))
+}
+
+#[pyfunction]
+/// Calculate the derivatives of the stationary point of the scalar potential
+///     g(x, args)
+/// w.r.t. the parameters, given the real stationary point.
+///
+/// The parameters have to be dual numbers of type Dual64, Dual2_64, Dual3_64, HyperDual64,
+/// DualDual64, Dual32, or HyperDual32.
+///
+/// Parameters
+/// ----------
+/// g : callable
+///     The potential as a function of the list of variables and the parameters.
+/// x : numpy.ndarray or [float]
+///     The real stationary point, e.g., obtained with scipy.optimize.minimize.
+/// args : dual number or list, tuple, or dict containing dual numbers
+///     The parameters.
+///
+/// Returns
+/// -------
+/// the stationary point as list of dual numbers of the same type as the parameters
+pub fn implicit_derivative_sp<'py>(
+    g: &Bound<'py, PyAny>,
+    x: &Bound<'py, PyAny>,
+    args: &Bound<'py, PyAny>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let x = super::extract_vector(x, "x", "implicit_derivative")?;
+    dispatch!(
+        "args",
+        args,
+        [
+            (PyDual64, Dual64),
+            (PyDual2_64, Dual2_64),
+            (PyDual3_64, Dual3_64),
+            (PyHyperDual64, HyperDual64),
+            (PyDualDual64, Dual<Dual64, f64>),
+            (PyDual32, Dual32),
+            (PyHyperDual32, HyperDual32)
+        ],
+        implicit_sp(g, x, args)
+    )
+}
+
+#[pyclass(module = "num_dual", name = "ImplicitDerivative", frozen)]
+/// Helper class that stores the parameters of the implicit function
+///     g(x, args) = 0
+/// in dual and real form and evaluates real residuals (for external solvers)
+/// and implicit derivatives.
+///
+/// Parameters
+/// ----------
+/// g : callable
+///     The residual as a function of the variable(s) and the parameters.
+/// args : dual number or list, tuple, or dict containing dual numbers
+///     The parameters.
+///
+/// Examples
+/// --------
+///
+/// >>> from num_dual import Dual64, ImplicitDerivative
+/// >>> implicit = ImplicitDerivative(lambda x, p: x * x - p, Dual64(4.0, 1.0))
+/// >>> implicit.residual(2.0)
+/// 0.0
+/// >>> implicit.implicit_derivative(2.0)
+/// 2 + 0.25ε
+pub struct PyImplicitDerivative {
+    g: Py<PyAny>,
+    base: Py<PyAny>,
+    derivative: Py<PyAny>,
+}
+
+#[pymethods]
+impl PyImplicitDerivative {
+    #[new]
+    fn new(g: &Bound<'_, PyAny>, args: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let base = dispatch!("args", args, real_parameters(args))?;
+        Ok(Self {
+            g: g.clone().unbind(),
+            base: base.unbind(),
+            derivative: args.clone().unbind(),
+        })
+    }
+
+    #[pyo3(signature = (*x))]
+    /// Evaluate the residual with the real parts of the parameters.
+    ///
+    /// Parameters
+    /// ----------
+    /// x : float, numpy.ndarray, or [float]
+    ///     The variable(s) in the same form as in the residual.
+    ///
+    /// Returns
+    /// -------
+    /// the real residual
+    fn residual<'py>(&self, x: &Bound<'py, PyTuple>) -> PyResult<Bound<'py, PyAny>> {
+        let py = x.py();
+        let mut args: Vec<_> = x.iter().collect();
+        args.push(self.base.bind(py).clone());
+        self.g.bind(py).call1(PyTuple::new(py, args)?)
+    }
+
+    #[pyo3(signature = (*x))]
+    /// Evaluate the implicit derivative, given the real solution.
+    ///
+    /// Depending on the arguments, `implicit_derivative`, `implicit_derivative_binary`,
+    /// or `implicit_derivative_vec` is evaluated.
+    ///
+    /// Parameters
+    /// ----------
+    /// x : float, (float, float), numpy.ndarray, or [float]
+    ///     The real solution, e.g., obtained with scipy.optimize.
+    ///
+    /// Returns
+    /// -------
+    /// the solution as dual number(s) of the same type as the parameters
+    fn implicit_derivative<'py>(&self, x: &Bound<'py, PyTuple>) -> PyResult<Bound<'py, PyAny>> {
+        let py = x.py();
+        let g = self.g.bind(py);
+        let args = self.derivative.bind(py);
+        match x.len() {
+            1 => {
+                let x = x.get_item(0)?;
+                if x.is_instance_of::<PyFloat>() || x.is_instance_of::<PyInt>() {
+                    implicit_derivative(g, x.extract()?, args)
+                } else {
+                    Ok(PyList::new(py, implicit_derivative_vec(g, &x, args)?)?.into_any())
+                }
+            }
+            2 => {
+                let (x, y) = x.extract()?;
+                let (x, y) = implicit_derivative_binary(g, x, y, args)?;
+                Ok(PyTuple::new(py, [x, y])?.into_any())
+            }
+            n => Err(PyErr::new::<PyTypeError, _>(format!(
+                "implicit_derivative() takes 1 or 2 positional arguments but {n} were given."
+            ))),
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use super::PyDualNumber;
+use crate::linalg::{LU, jacobi_eigenvalue};
+use crate::*;
+use nalgebra::{DMatrix, DVector};
+use numpy::PyArray;
+use numpy::ndarray::{Array1, Array2};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+
+/// Extract the entries of a square matrix from a two-dimensional numpy array or nested lists.
+fn extract_matrix<'py>(a: &Bound<'py, PyAny>) -> PyResult<Vec<Vec<Bound<'py, PyAny>>>> {
+    let a: Vec<Vec<_>> = a.extract().map_err(|_| {
+        PyErr::new::<PyTypeError, _>(
+            "argument 'a' must be a two-dimensional array or a list of lists.".to_string(),
+        )
+    })?;
+    if a.iter().any(|r| r.len() != a.len()) {
+        return Err(PyErr::new::<PyValueError, _>(
+            "argument 'a' must be a square matrix.".to_string(),
+        ));
+    }
+    Ok(a)
+}
+
+/// Python list of all entries of a matrix, used to determine the type of the dual numbers.
+fn entries<'py>(
+    py: Python<'py>,
+    a: &[Vec<Bound<'py, PyAny>>],
+    b: &[Bound<'py, PyAny>],
+) -> PyResult<Bound<'py, PyAny>> {
+    let entries: Vec<_> = a.iter().flatten().chain(b).collect();
+    Ok(PyList::new(py, entries)?.into_any())
+}
+
+/// Convert an entry that is either a dual number of type `P` or a float.
+fn entry<D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    x: &Bound<'_, PyAny>,
+    name: &str,
+) -> PyResult<D> {
+    super::extract_dual::<D, F, P>(x).ok_or_else(|| {
+        PyErr::new::<PyTypeError, _>(format!(
+            "argument '{name}' must only contain dual numbers of type {} and floats.",
+            P::NAME
+        ))
+    })
+}
+
+fn matrix<D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    a: &[Vec<Bound<'_, PyAny>>],
+) -> PyResult<DMatrix<D>> {
+    let n = a.len();
+    let a = a
+        .iter()
+        .flatten()
+        .map(|x| entry::<D, P, F>(x, "a"))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(DMatrix::from_row_slice(n, n, &a))
+}
+
+fn lu<D: DualNum<F>, F: DualNumFloat>(a: DMatrix<D>) -> PyResult<LU<D, F, nalgebra::Dyn>> {
+    LU::new(a).map_err(|e| PyErr::new::<PyValueError, _>(e.to_string()))
+}
+
+fn dual<'py, D, P: PyDualNumber<D>>(py: Python<'py>, x: D) -> PyResult<Bound<'py, PyAny>> {
+    Ok(Bound::new(py, P::from(x))?.into_any())
+}
+
+fn array1<'py, D: Clone, P: PyDualNumber<D>>(
+    py: Python<'py>,
+    x: &DVector<D>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let x = x
+        .iter()
+        .map(|x| Ok(dual::<D, P>(py, x.clone())?.unbind()))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyArray::from_owned_object_array(py, Array1::from(x)).into_any())
+}
+
+fn array2<'py, D: Clone, P: PyDualNumber<D>>(
+    py: Python<'py>,
+    x: &DMatrix<D>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let (n, m) = x.shape();
+    let entries = (0..n)
+        .flat_map(|i| (0..m).map(move |j| x[(i, j)].clone()))
+        .map(|x| Ok(dual::<D, P>(py, x)?.unbind()))
+        .collect::<PyResult<Vec<_>>>()?;
+    let x = Array2::from_shape_vec((n, m), entries).unwrap();
+    Ok(PyArray::from_owned_object_array(py, x).into_any())
+}
+
+fn solve_dual<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    py: Python<'py>,
+    a: &[Vec<Bound<'py, PyAny>>],
+    b: &[Bound<'py, PyAny>],
+) -> PyResult<Bound<'py, PyAny>> {
+    if b.len() != a.len() {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "argument 'b' must have {} entries, got {}.",
+            a.len(),
+            b.len()
+        )));
+    }
+    let b = b
+        .iter()
+        .map(|x| entry::<D, P, F>(x, "b"))
+        .collect::<PyResult<Vec<_>>>()?;
+    let x = lu(matrix::<D, P, F>(a)?)?.solve(&DVector::from(b));
+    array1::<D, P>(py, &x)
+}
+
+fn det_dual<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    py: Python<'py>,
+    a: &[Vec<Bound<'py, PyAny>>],
+) -> PyResult<Bound<'py, PyAny>> {
+    let det = lu(matrix::<D, P, F>(a)?)?.determinant();
+    dual::<D, P>(py, det)
+}
+
+fn inv_dual<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    py: Python<'py>,
+    a: &[Vec<Bound<'py, PyAny>>],
+) -> PyResult<Bound<'py, PyAny>> {
+    let inv = lu(matrix::<D, P, F>(a)?)?.inverse();
+    array2::<D, P>(py, &inv)
+}
+
+fn smallest_ev_dual<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    py: Python<'py>,
+    a: &[Vec<Bound<'py, PyAny>>],
+) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let (e, v) = crate::linalg::smallest_ev(matrix::<D, P, F>(a)?);
+    Ok((dual::<D, P>(py, e)?, array1::<D, P>(py, &v)?))
+}
+
+fn eigh_dual<'py, D: DualNum<F>, P: PyDualNumber<D>, F: DualNumFloat>(
+    py: Python<'py>,
+    a: &[Vec<Bound<'py, PyAny>>],
+    max_iter: usize,
+) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let (e, v) = jacobi_eigenvalue(matrix::<D, P, F>(a)?, max_iter);
+    Ok((array1::<D, P>(py, &e)?, array2::<D, P>(py, &v)?))
+}
+
+#[pyfunction]
+/// Solve the linear system a x = b using an LU decomposition with partial pivoting.
+///
+/// Parameters
+/// ----------
+/// a : numpy.ndarray or [[dual number]]
+///     The square matrix of dual numbers (or floats).
+/// b : numpy.ndarray or [dual number]
+///     The right-hand side.
+///
+/// Returns
+/// -------
+/// the solution as numpy.ndarray of dual numbers
+pub fn solve<'py>(a: &Bound<'py, PyAny>, b: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = a.py();
+    let a = extract_matrix(a)?;
+    let b: Vec<_> = b.extract().map_err(|_| {
+        PyErr::new::<PyTypeError, _>(
+            "argument 'b' must be a one-dimensional array or a list.".to_string(),
+        )
+    })?;
+    dispatch!("a", &entries(py, &a, &b)?, solve_dual(py, &a, &b))
+}
+
+#[pyfunction]
+/// Calculate the determinant of a non-singular matrix.
+///
+/// Parameters
+/// ----------
+/// a : numpy.ndarray or [[dual number]]
+///     The square matrix of dual numbers (or floats).
+///
+/// Returns
+/// -------
+/// the determinant as dual number
+///
+/// Examples
+///
+/// All dual number classes are supported, including 32-bit and nested dual numbers and the
+/// statically and dynamically sized ones used by `gradient`, `hessian`, and `partial_hessian`.
+///
+/// >>> from num_dual import gradient, hessian, partial_hessian, linalg
+/// >>> f = lambda x: linalg.det([[x[0], x[1]], [x[1], x[0]]])
+/// >>> _, g = gradient(f, [2.0, 1.0])
+/// >>> g
+/// array([ 4., -2.])
+/// >>> _, _, h = hessian(f, [2.0, 1.0])
+/// >>> h
+/// array([[ 2.,  0.],
+///        [ 0., -2.]])
+/// >>> _, _, _, h = partial_hessian(lambda x, y: linalg.det([[x[0], y[0]], [y[0], x[0] * y[0]]]), [2.0], [1.0])
+/// >>> h
+/// array([[4.]])
+/// >>> _, g = gradient(lambda x: linalg.det([[x[i] if i == j else 0.0 for j in range(20)] for i in range(20)]), [1.0] * 20)
+/// >>> print(g)
+/// [1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1. 1.]
+pub fn det<'py>(a: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = a.py();
+    let a = extract_matrix(a)?;
+    dispatch!("a", &entries(py, &a, &[])?, det_dual(py, &a))
+}
+
+#[pyfunction]
+/// Calculate the inverse of a matrix.
+///
+/// Parameters
+/// ----------
+/// a : numpy.ndarray or [[dual number]]
+///     The square matrix of dual numbers (or floats).
+///
+/// Returns
+/// -------
+/// the inverse as numpy.ndarray of dual numbers
+pub fn inv<'py>(a: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = a.py();
+    let a = extract_matrix(a)?;
+    dispatch!("a", &entries(py, &a, &[])?, inv_dual(py, &a))
+}
+
+#[pyfunction]
+/// Calculate the smallest eigenvalue and the corresponding eigenvector of a symmetric matrix.
+///
+/// Parameters
+/// ----------
+/// a : numpy.ndarray or [[dual number]]
+///     The symmetric matrix of dual numbers (or floats).
+///
+/// Returns
+/// -------
+/// the smallest eigenvalue as dual number and the eigenvector as numpy.ndarray of dual numbers
+pub fn smallest_ev<'py>(a: &Bound<'py, PyAny>) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let py = a.py();
+    let a = extract_matrix(a)?;
+    dispatch!("a", &entries(py, &a, &[])?, smallest_ev_dual(py, &a))
+}
+
+#[pyfunction]
+#[pyo3(signature = (a, max_iter=200))]
+/// Calculate the eigenvalues and eigenvectors of a symmetric matrix using the Jacobi
+/// eigenvalue algorithm.
+///
+/// Parameters
+/// ----------
+/// a : numpy.ndarray or [[dual number]]
+///     The symmetric matrix of dual numbers (or floats).
+/// max_iter : int, optional
+///     The maximum number of Jacobi sweeps.
+///
+/// Returns
+/// -------
+/// the eigenvalues in ascending order and the eigenvectors (as columns) as numpy.ndarray of dual numbers
+pub fn eigh<'py>(
+    a: &Bound<'py, PyAny>,
+    max_iter: usize,
+) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let py = a.py();
+    let a = extract_matrix(a)?;
+    dispatch!("a", &entries(py, &a, &[])?, eigh_dual(py, &a, max_iter))
+}
@@ -1,39 +1,302 @@
 #![allow(non_snake_case)]
+use crate::{DualNum, DualNumFloat};
+use nalgebra::{Dim, Matrix, RawStorage};
+use numpy::ndarray::{Array2, ArrayD};
+use numpy::{IntoPyArray, PyArray2, PyReadonlyArray1, PyReadonlyArray2, PyReadonlyArrayDyn};
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyFloat, PyInt, PyList, PyTuple};
 use pyo3::wrap_pyfunction;
+use pyo3::{PyClass, PyClassInitializer};
+
+/// Call `$f::<D, P, F>` for the type `P` of the dual numbers contained in the argument `$name`.
+///
+/// Without an explicit list of classes, all dual number classes are considered.
+macro_rules! dispatch {
+    (@ $name:literal, $obj:expr, $expected:expr, [$(($py_type_name:ty, $data_type:ty)),+], $f:ident $a:tt) => {{
+        $(
+            if super::contains::<$py_type_name>($obj) {
+                $f::<$data_type, $py_type_name, _> $a
+            } else
+        )+
+        {
+            Err(PyErr::new::<PyTypeError, _>(format!(
+                "argument '{}' must contain dual numbers{}.",
+                $name,
+                $expected
+            )))
+        }
+    }};
+    ($name:literal, $obj:expr, [$(($py_type_name:ty, $data_type:ty)),+], $f:ident $a:tt) => {
+        dispatch!(
+            @ $name,
+            $obj,
+            format!(
+                " of type {}",
+                [$(<$py_type_name as pyo3::PyTypeInfo>::NAME),+].join(", ")
+            ),
+            [$(($py_type_name, $data_type)),+],
+            $f $a
+        )
+    };
+    ($name:literal, $obj:expr, $f:ident $a:tt) => {
+        dispatch!(
+            @ $name,
+            $obj,
+            "",
+            [
+                (super::dual::PyDual64, Dual64),
+                (super::dual2::PyDual2_64, Dual2_64),
+                (super::dual3::PyDual3_64, Dual3_64),
+                (super::hyperdual::PyHyperDual64, HyperDual64),
+                (super::hyperhyperdual::PyHyperHyperDual64, HyperHyperDual64),
+                (super::dual::PyDual32, Dual32),
+                (super::dual2::PyDual2_32, Dual2_32),
+                (super::dual3::PyDual3_32, Dual3_32),
+                (super::hyperdual::PyHyperDual32, HyperDual32),
+                (super::hyperhyperdual::PyHyperHyperDual32, HyperHyperDual32),
+                (super::dual::PyDualDual64, Dual<Dual64, f64>),
+                (super::dual::PyDualDual2_64, Dual<Dual2_64, f64>),
+                (super::dual::PyDualDual3_64, Dual<Dual3_64, f64>),
+                (super::dual::PyDualHyperDual64, Dual<HyperDual64, f64>),
+                (super::dual::PyDualDual64Dyn, Dual<DualDVec64, f64>),
+                (super::dual::PyDualDual2_64Dyn, Dual<Dual2DVec64, f64>),
+                (super::dual::PyDualHyperDual64Dyn, Dual<HyperDualDVec64, f64>),
+                (super::dual2::PyDual2Dual64, Dual2<Dual64, f64>),
+                (super::dual2::PyDual2Dual2_64, Dual2<Dual2_64, f64>),
+                (super::dual2::PyDual2Dual3_64, Dual2<Dual3_64, f64>),
+                (super::dual2::PyDual2HyperDual64, Dual2<HyperDual64, f64>),
+                (super::dual2::PyDual2Dual64Dyn, Dual2<DualDVec64, f64>),
+                (super::dual2::PyDual2Dual2_64Dyn, Dual2<Dual2DVec64, f64>),
+                (super::dual2::PyDual2HyperDual64Dyn, Dual2<HyperDualDVec64, f64>),
+                (super::dual3::PyDual3Dual64, Dual3<Dual64, f64>),
+                (super::dual3::PyDual3Dual2_64, Dual3<Dual2_64, f64>),
+                (super::dual3::PyDual3Dual3_64, Dual3<Dual3_64, f64>),
+                (super::dual3::PyDual3HyperDual64, Dual3<HyperDual64, f64>),
+                (super::dual3::PyDual3Dual64Dyn, Dual3<DualDVec64, f64>),
+                (super::dual3::PyDual3Dual2_64Dyn, Dual3<Dual2DVec64, f64>),
+                (super::dual3::PyDual3HyperDual64Dyn, Dual3<HyperDualDVec64, f64>),
+                (super::hyperdual::PyHyperDualDual64, HyperDual<Dual64, f64>),
+                (super::hyperdual::PyHyperDualDual2_64, HyperDual<Dual2_64, f64>),
+                (super::hyperdual::PyHyperDualDual3_64, HyperDual<Dual3_64, f64>),
+                (super::hyperdual::PyHyperDualHyperDual64, HyperDual<HyperDual64, f64>),
+                (super::hyperdual::PyHyperDualDual64Dyn, HyperDual<DualDVec64, f64>),
+                (super::hyperdual::PyHyperDualDual2_64Dyn, HyperDual<Dual2DVec64, f64>),
+                (super::hyperdual::PyHyperDualHyperDual64Dyn, HyperDual<HyperDualDVec64, f64>),
+                (super::dual::PyDual64_1, DualSVec64<1>),
+                (super::dual::PyDual64_2, DualSVec64<2>),
+                (super::dual::PyDual64_3, DualSVec64<3>),
+                (super::dual::PyDual64_4, DualSVec64<4>),
+                (super::dual::PyDual64_5, DualSVec64<5>),
+                (super::dual::PyDual64_6, DualSVec64<6>),
+                (super::dual::PyDual64_7, DualSVec64<7>),
+                (super::dual::PyDual64_8, DualSVec64<8>),
+                (super::dual::PyDual64_9, DualSVec64<9>),
+                (super::dual::PyDual64_10, DualSVec64<10>),
+                (super::dual::PyDual64_11, DualSVec64<11>),
+                (super::dual::PyDual64_12, DualSVec64<12>),
+                (super::dual::PyDual64_13, DualSVec64<13>),
+                (super::dual::PyDual64_14, DualSVec64<14>),
+                (super::dual::PyDual64_15, DualSVec64<15>),
+                (super::dual::PyDual64_16, DualSVec64<16>),
+                (super::dual::PyDual64Dyn, DualDVec64),
+                (super::dual2::PyDual2_64_1, Dual2SVec64<1>),
+                (super::dual2::PyDual2_64_2, Dual2SVec64<2>),
+                (super::dual2::PyDual2_64_3, Dual2SVec64<3>),
+                (super::dual2::PyDual2_64_4, Dual2SVec64<4>),
+                (super::dual2::PyDual2_64_5, Dual2SVec64<5>),
+                (super::dual2::PyDual2_64_6, Dual2SVec64<6>),
+                (super::dual2::PyDual2_64_7, Dual2SVec64<7>),
+                (super::dual2::PyDual2_64_8, Dual2SVec64<8>),
+                (super::dual2::PyDual2_64_9, Dual2SVec64<9>),
+                (super::dual2::PyDual2_64_10, Dual2SVec64<10>),
+                (super::dual2::PyDual2_64_11, Dual2SVec64<11>),
+                (super::dual2::PyDual2_64_12, Dual2SVec64<12>),
+                (super::dual2::PyDual2_64_13, Dual2SVec64<13>),
+                (super::dual2::PyDual2_64_14, Dual2SVec64<14>),
+                (super::dual2::PyDual2_64_15, Dual2SVec64<15>),
+                (super::dual2::PyDual2_64_16, Dual2SVec64<16>),
+                (super::dual2::PyDual2_64Dyn, Dual2DVec64),
+                (super::hyperdual::PyHyperDual64_1_1, HyperDualSVec64<1, 1>),
+                (super::hyperdual::PyHyperDual64_1_2, HyperDualSVec64<1, 2>),
+                (super::hyperdual::PyHyperDual64_1_3, HyperDualSVec64<1, 3>),
+                (super::hyperdual::PyHyperDual64_1_4, HyperDualSVec64<1, 4>),
+                (super::hyperdual::PyHyperDual64_1_5, HyperDualSVec64<1, 5>),
+                (super::hyperdual::PyHyperDual64_2_1, HyperDualSVec64<2, 1>),
+                (super::hyperdual::PyHyperDual64_2_2, HyperDualSVec64<2, 2>),
+                (super::hyperdual::PyHyperDual64_2_3, HyperDualSVec64<2, 3>),
+                (super::hyperdual::PyHyperDual64_2_4, HyperDualSVec64<2, 4>),
+                (super::hyperdual::PyHyperDual64_2_5, HyperDualSVec64<2, 5>),
+                (super::hyperdual::PyHyperDual64_3_1, HyperDualSVec64<3, 1>),
+                (super::hyperdual::PyHyperDual64_3_2, HyperDualSVec64<3, 2>),
+                (super::hyperdual::PyHyperDual64_3_3, HyperDualSVec64<3, 3>),
+                (super::hyperdual::PyHyperDual64_3_4, HyperDualSVec64<3, 4>),
+                (super::hyperdual::PyHyperDual64_3_5, HyperDualSVec64<3, 5>),
+                (super::hyperdual::PyHyperDual64_4_1, HyperDualSVec64<4, 1>),
+                (super::hyperdual::PyHyperDual64_4_2, HyperDualSVec64<4, 2>),
+                (super::hyperdual::PyHyperDual64_4_3, HyperDualSVec64<4, 3>),
+                (super::hyperdual::PyHyperDual64_4_4, HyperDualSVec64<4, 4>),
+                (super::hyperdual::PyHyperDual64_4_5, HyperDualSVec64<4, 5>),
+                (super::hyperdual::PyHyperDual64_5_1, HyperDualSVec64<5, 1>),
+                (super::hyperdual::PyHyperDual64_5_2, HyperDualSVec64<5, 2>),
+                (super::hyperdual::PyHyperDual64_5_3, HyperDualSVec64<5, 3>),
+                (super::hyperdual::PyHyperDual64_5_4, HyperDualSVec64<5, 4>),
+                (super::hyperdual::PyHyperDual64_5_5, HyperDualSVec64<5, 5>),
+                (super::hyperdual::PyHyperDual64Dyn, HyperDualDVec64)
+            ],
+            $f $a
+        )
+    };
+}
 
 mod dual;
 mod dual2;
 mod dual3;
 mod hyperdual;
 mod hyperhyperdual;
+mod implicit;
+mod linalg;
+mod special;
 
-use dual::{first_derivative, gradient, jacobian};
+use dual::{first_derivative, first_derivative_batch, gradient, gradient_batch, jacobian};
 use dual2::{hessian, second_derivative};
 use dual3::third_derivative;
 use hyperdual::{partial_hessian, second_partial_derivative};
 use hyperhyperdual::{third_partial_derivative, third_partial_derivative_vec};
+use implicit::{
+    PyImplicitDerivative, implicit_derivative, implicit_derivative_binary, implicit_derivative_sp,
+    implicit_derivative_vec,
+};
+use special::{bessel_j0, bessel_j1, bessel_j2};
 
 pub use dual::{
-    PyDual64, PyDual64_2, PyDual64_3, PyDual64_4, PyDual64_5, PyDual64_6, PyDual64_7, PyDual64_8,
-    PyDual64_9, PyDual64_10, PyDual64_11, PyDual64_12, PyDual64_13, PyDual64_14, PyDual64_15,
-    PyDual64_16, PyDual64Dyn,
+    PyDual32, PyDual64, PyDual64_2, PyDual64_3, PyDual64_4, PyDual64_5, PyDual64_6, PyDual64_7,
+    PyDual64_8, PyDual64_9, PyDual64_10, PyDual64_11, PyDual64_12, PyDual64_13, PyDual64_14,
+    PyDual64_15, PyDual64_16, PyDual64Dyn, PyDualDual2_64, PyDualDual2_64Dyn, PyDualDual3_64,
+    PyDualDual64, PyDualDual64Dyn, PyDualHyperDual64, PyDualHyperDual64Dyn,
 };
 pub use dual2::{
-    PyDual2_64, PyDual2_64_1, PyDual2_64_2, PyDual2_64_3, PyDual2_64_4, PyDual2_64_5, PyDual2_64_6,
-    PyDual2_64_7, PyDual2_64_8, PyDual2_64_9, PyDual2_64_10, PyDual2_64_11, PyDual2_64_12,
-    PyDual2_64_13, PyDual2_64_14, PyDual2_64_15, PyDual2_64_16, PyDual2_64Dyn, PyDual2Dual64,
+    PyDual2_32, PyDual2_64, PyDual2_64_1, PyDual2_64_2, PyDual2_64_3, PyDual2_64_4, PyDual2_64_5,
+    PyDual2_64_6, PyDual2_64_7, PyDual2_64_8, PyDual2_64_9, PyDual2_64_10, PyDual2_64_11,
+    PyDual2_64_12, PyDual2_64_13, PyDual2_64_14, PyDual2_64_15, PyDual2_64_16, PyDual2_64Dyn,
+    PyDual2Dual2_64, PyDual2Dual2_64Dyn, PyDual2Dual3_64, PyDual2Dual64, PyDual2Dual64Dyn,
+    PyDual2HyperDual64, PyDual2HyperDual64Dyn,
+};
+pub use dual3::{
+    PyDual3_32, PyDual3_64, PyDual3Dual2_64, PyDual3Dual2_64Dyn, PyDual3Dual3_64, PyDual3Dual64,
+    PyDual3Dual64Dyn, PyDual3HyperDual64, PyDual3HyperDual64Dyn,
 };
-pub use dual3::{PyDual3_64, PyDual3Dual64};
 pub use hyperdual::{
-    PyHyperDual64, PyHyperDual64_1_2, PyHyperDual64_1_3, PyHyperDual64_1_4, PyHyperDual64_1_5,
-    PyHyperDual64_2_1, PyHyperDual64_2_2, PyHyperDual64_2_3, PyHyperDual64_2_4, PyHyperDual64_2_5,
-    PyHyperDual64_3_1, PyHyperDual64_3_2, PyHyperDual64_3_3, PyHyperDual64_3_4, PyHyperDual64_3_5,
-    PyHyperDual64_4_1, PyHyperDual64_4_2, PyHyperDual64_4_3, PyHyperDual64_4_4, PyHyperDual64_4_5,
-    PyHyperDual64_5_1, PyHyperDual64_5_2, PyHyperDual64_5_3, PyHyperDual64_5_4, PyHyperDual64_5_5,
-    PyHyperDual64Dyn, PyHyperDualDual64,
+    PyHyperDual32, PyHyperDual64, PyHyperDual64_1_2, PyHyperDual64_1_3, PyHyperDual64_1_4,
+    PyHyperDual64_1_5, PyHyperDual64_2_1, PyHyperDual64_2_2, PyHyperDual64_2_3, PyHyperDual64_2_4,
+    PyHyperDual64_2_5, PyHyperDual64_3_1, PyHyperDual64_3_2, PyHyperDual64_3_3, PyHyperDual64_3_4,
+    PyHyperDual64_3_5, PyHyperDual64_4_1, PyHyperDual64_4_2, PyHyperDual64_4_3, PyHyperDual64_4_4,
+    PyHyperDual64_4_5, PyHyperDual64_5_1, PyHyperDual64_5_2, PyHyperDual64_5_3, PyHyperDual64_5_4,
+    PyHyperDual64_5_5, PyHyperDual64Dyn, PyHyperDualDual2_64, PyHyperDualDual2_64Dyn,
+    PyHyperDualDual3_64, PyHyperDualDual64, PyHyperDualDual64Dyn, PyHyperDualHyperDual64,
+    PyHyperDualHyperDual64Dyn,
 };
-pub use hyperhyperdual::PyHyperHyperDual64;
+pub use hyperhyperdual::{PyHyperHyperDual32, PyHyperHyperDual64};
+
+/// Python classes of dual numbers that wrap the Rust type `D`.
+trait PyDualNumber<D>: PyClass + Clone + From<D> + Into<D> + Into<PyClassInitializer<Self>> {}
+
+impl<D, P: PyClass + Clone + From<D> + Into<D> + Into<PyClassInitializer<Self>>> PyDualNumber<D>
+    for P
+{
+}
+
+/// Check whether `obj` is a dual number of type `P` or a list, tuple, or dict that contains one.
+fn contains<P: PyClass>(obj: &Bound<'_, PyAny>) -> bool {
+    if obj.is_instance_of::<P>() {
+        true
+    } else if let Ok(list) = obj.cast::<PyList>() {
+        list.iter().any(|a| contains::<P>(&a))
+    } else if let Ok(tuple) = obj.cast::<PyTuple>() {
+        tuple.iter().any(|a| contains::<P>(&a))
+    } else if let Ok(dict) = obj.cast::<PyDict>() {
+        dict.values().iter().any(|a| contains::<P>(&a))
+    } else {
+        false
+    }
+}
+
+/// Convert an object that is either a dual number of type `P` or a real number.
+fn extract_dual<D: DualNum<F>, F: DualNumFloat, P: PyDualNumber<D>>(
+    x: &Bound<'_, PyAny>,
+) -> Option<D> {
+    if let Ok(x) = x.cast::<P>() {
+        Some(x.borrow().clone().into())
+    } else if x.is_instance_of::<PyFloat>() || x.is_instance_of::<PyInt>() {
+        x.extract::<f64>().ok().and_then(F::from_f64).map(D::from)
+    } else {
+        None
+    }
+}
+
+/// Extract an array of points from a numpy array of arbitrary dimension or a Python sequence.
+fn extract_array(x: &Bound<'_, PyAny>, name: &str) -> PyResult<ArrayD<f64>> {
+    if let Ok(x) = x.extract::<PyReadonlyArrayDyn<f64>>() {
+        return Ok(x.as_array().to_owned());
+    }
+    let x = x.extract::<Vec<f64>>().map_err(|_| {
+        PyErr::new::<PyTypeError, _>(format!(
+            "argument '{name}': must be a list or an array of floats."
+        ))
+    })?;
+    Ok(ArrayD::from_shape_vec(vec![x.len()], x).unwrap())
+}
+
+/// Extract points (one per row) from a two-dimensional numpy array or a list of lists.
+fn extract_points(x: &Bound<'_, PyAny>, name: &str) -> PyResult<Array2<f64>> {
+    if let Ok(x) = x.extract::<PyReadonlyArray2<f64>>() {
+        return Ok(x.as_array().to_owned());
+    }
+    let error = || {
+        PyErr::new::<PyTypeError, _>(format!(
+            "argument '{name}': must be a two-dimensional array or a list of lists with one point per row."
+        ))
+    };
+    let x = x.extract::<Vec<Vec<f64>>>().map_err(|_| error())?;
+    let n = x.first().map_or(0, |x| x.len());
+    if x.iter().any(|x| x.len() != n) {
+        return Err(error());
+    }
+    Ok(Array2::from_shape_vec((x.len(), n), x.concat()).unwrap())
+}
+
+/// Extract a vector of floats from a one-dimensional numpy array or a Python sequence.
+fn extract_vector(
+    x: &Bound<'_, PyAny>,
+    name: &str,
+    scalar_alternative: &str,
+) -> PyResult<Vec<f64>> {
+    if let Ok(x) = x.extract::<PyReadonlyArray1<f64>>() {
+        return Ok(x.as_array().to_vec());
+    }
+    x.extract::<Vec<f64>>().map_err(|_| {
+        PyErr::new::<PyTypeError, _>(format!(
+            "argument '{name}': must be a list or a one-dimensional array. For univariate functions use '{scalar_alternative}' instead."
+        ))
+    })
+}
+
+/// Convert a matrix into a two-dimensional numpy array.
+fn matrix_to_pyarray<'py, R: Dim, C: Dim, S: RawStorage<f64, R, C>>(
+    py: Python<'py>,
+    m: &Matrix<f64, R, C, S>,
+) -> Bound<'py, PyArray2<f64>> {
+    Array2::from_shape_fn((m.nrows(), m.ncols()), |(i, j)| m[(i, j)]).into_pyarray(py)
+}
+
+/// Register the classes of dual numbers with statically or dynamically sized derivatives. They
+/// are created by the derivative functions, but are registered so that they can be used for type
+/// checks and pickling.
+macro_rules! add_classes {
+    ($m:ident, [$($py_type_name:ty),+ $(,)?]) => {
+        $($m.add_class::<$py_type_name>()?;)+
+    };
+}
 
 #[pymodule]
 pub fn num_dual(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -46,8 +309,109 @@ pub fn num_dual(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDual2Dual64>()?;
     m.add_class::<PyDual3Dual64>()?;
     m.add_class::<PyHyperHyperDual64>()?;
+    m.add_class::<PyDual32>()?;
+    m.add_class::<PyHyperDual32>()?;
+    m.add_class::<PyDual2_32>()?;
+    m.add_class::<PyDual3_32>()?;
+    m.add_class::<PyHyperHyperDual32>()?;
+    // Nested dual numbers are only available for the outer types Dual, Dual2, Dual3, and HyperDual
+    // over Dual64, Dual2_64, Dual3_64, and HyperDual64, and over Dual64Dyn, Dual2_64Dyn, and
+    // HyperDual64Dyn. There are no nested classes over 32-bit dual numbers or dual numbers with
+    // statically sized derivatives (this set is also stated in num_dual.pyi and docs/api.rst).
+    m.add_class::<PyDualDual64>()?;
+    m.add_class::<PyDualDual2_64>()?;
+    m.add_class::<PyDualDual3_64>()?;
+    m.add_class::<PyDualHyperDual64>()?;
+    m.add_class::<PyHyperDualDual2_64>()?;
+    m.add_class::<PyHyperDualDual3_64>()?;
+    m.add_class::<PyHyperDualHyperDual64>()?;
+    m.add_class::<PyDual2Dual2_64>()?;
+    m.add_class::<PyDual2Dual3_64>()?;
+    m.add_class::<PyDual2HyperDual64>()?;
+    m.add_class::<PyDual3Dual2_64>()?;
+    m.add_class::<PyDual3Dual3_64>()?;
+    m.add_class::<PyDual3HyperDual64>()?;
+    m.add_class::<PyDualDual64Dyn>()?;
+    m.add_class::<PyDualDual2_64Dyn>()?;
+    m.add_class::<PyDualHyperDual64Dyn>()?;
+    m.add_class::<PyDual2Dual64Dyn>()?;
+    m.add_class::<PyDual2Dual2_64Dyn>()?;
+    m.add_class::<PyDual2HyperDual64Dyn>()?;
+    m.add_class::<PyDual3Dual64Dyn>()?;
+    m.add_class::<PyDual3Dual2_64Dyn>()?;
+    m.add_class::<PyDual3HyperDual64Dyn>()?;
+    m.add_class::<PyHyperDualDual64Dyn>()?;
+    m.add_class::<PyHyperDualDual2_64Dyn>()?;
+    m.add_class::<PyHyperDualHyperDual64Dyn>()?;
+    add_classes!(
+        m,
+        [
+            dual::PyDual64_1,
+            dual::PyDual64_2,
+            dual::PyDual64_3,
+            dual::PyDual64_4,
+            dual::PyDual64_5,
+            dual::PyDual64_6,
+            dual::PyDual64_7,
+            dual::PyDual64_8,
+            dual::PyDual64_9,
+            dual::PyDual64_10,
+            dual::PyDual64_11,
+            dual::PyDual64_12,
+            dual::PyDual64_13,
+            dual::PyDual64_14,
+            dual::PyDual64_15,
+            dual::PyDual64_16,
+            dual::PyDual64Dyn,
+            dual2::PyDual2_64_1,
+            dual2::PyDual2_64_2,
+            dual2::PyDual2_64_3,
+            dual2::PyDual2_64_4,
+            dual2::PyDual2_64_5,
+            dual2::PyDual2_64_6,
+            dual2::PyDual2_64_7,
+            dual2::PyDual2_64_8,
+            dual2::PyDual2_64_9,
+            dual2::PyDual2_64_10,
+            dual2::PyDual2_64_11,
+            dual2::PyDual2_64_12,
+            dual2::PyDual2_64_13,
+            dual2::PyDual2_64_14,
+            dual2::PyDual2_64_15,
+            dual2::PyDual2_64_16,
+            dual2::PyDual2_64Dyn,
+            hyperdual::PyHyperDual64_1_1,
+            hyperdual::PyHyperDual64_1_2,
+            hyperdual::PyHyperDual64_1_3,
+            hyperdual::PyHyperDual64_1_4,
+            hyperdual::PyHyperDual64_1_5,
+            hyperdual::PyHyperDual64_2_1,
+            hyperdual::PyHyperDual64_2_2,
+            hyperdual::PyHyperDual64_2_3,
+            hyperdual::PyHyperDual64_2_4,
+            hyperdual::PyHyperDual64_2_5,
+            hyperdual::PyHyperDual64_3_1,
+            hyperdual::PyHyperDual64_3_2,
+            hyperdual::PyHyperDual64_3_3,
+            hyperdual::PyHyperDual64_3_4,
+            hyperdual::PyHyperDual64_3_5,
+            hyperdual::PyHyperDual64_4_1,
+            hyperdual::PyHyperDual64_4_2,
+            hyperdual::PyHyperDual64_4_3,
+            hyperdual::PyHyperDual64_4_4,
+            hyperdual::PyHyperDual64_4_5,
+            hyperdual::PyHyperDual64_5_1,
+            hyperdual::PyHyperDual64_5_2,
+            hyperdual::PyHyperDual64_5_3,
+            hyperdual::PyHyperDual64_5_4,
+            hyperdual::PyHyperDual64_5_5,
+            hyperdual::PyHyperDual64Dyn,
+        ]
+    );
     m.add_function(wrap_pyfunction!(first_derivative, m)?)?;
+    m.add_function(wrap_pyfunction!(first_derivative_batch, m)?)?;
     m.add_function(wrap_pyfunction!(gradient, m)?)?;
+    m.add_function(wrap_pyfunction!(gradient_batch, m)?)?;
     m.add_function(wrap_pyfunction!(jacobian, m)?)?;
     m.add_function(wrap_pyfunction!(second_derivative, m)?)?;
     m.add_function(wrap_pyfunction!(hessian, m)?)?;
@@ -56,5 +420,26 @@ pub fn num_dual(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(partial_hessian, m)?)?;
     m.add_function(wrap_pyfunction!(third_partial_derivative, m)?)?;
     m.add_function(wrap_pyfunction!(third_partial_derivative_vec, m)?)?;
+    m.add_function(wrap_pyfunction!(implicit_derivative, m)?)?;
+    m.add_function(wrap_pyfunction!(implicit_derivative_binary, m)?)?;
+    m.add_function(wrap_pyfunction!(implicit_derivative_vec, m)?)?;
+    m.add_function(wrap_pyfunction!(implicit_derivative_sp, m)?)?;
+    m.add_class::<PyImplicitDerivative>()?;
+    m.add_function(wrap_pyfunction!(bessel_j0, m)?)?;
+    m.add_function(wrap_pyfunction!(bessel_j1, m)?)?;
+    m.add_function(wrap_pyfunction!(bessel_j2, m)?)?;
+
+    let linalg = PyModule::new(m.py(), "linalg")?;
+    linalg.add_function(wrap_pyfunction!(linalg::solve, &linalg)?)?;
+    linalg.add_function(wrap_pyfunction!(linalg::det, &linalg)?)?;
+    linalg.add_function(wrap_pyfunction!(linalg::inv, &linalg)?)?;
+    linalg.add_function(wrap_pyfunction!(linalg::smallest_ev, &linalg)?)?;
+    linalg.add_function(wrap_pyfunction!(linalg::eigh, &linalg)?)?;
+    m.add_submodule(&linalg)?;
+    // make `import num_dual.linalg` work
+    m.py()
+        .import("sys")?
+        .getattr("modules")?
+        .set_item("num_dual.linalg", linalg)?;
     Ok(())
 }
@@ -0,0 +1,55 @@
+use crate::BesselDual;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyFloat, PyInt};
+
+/// Evaluate the special function `name` for a real number (using `f`) or a dual number of any type
+/// (using the method of the same name).
+fn special_function<'py>(
+    x: &Bound<'py, PyAny>,
+    name: &str,
+    f: fn(f64) -> f64,
+) -> PyResult<Bound<'py, PyAny>> {
+    if x.is_instance_of::<PyFloat>() || x.is_instance_of::<PyInt>() {
+        return Ok(PyFloat::new(x.py(), f(x.extract()?)).into_any());
+    }
+    if x.get_type().module()?.to_str()? == "num_dual" && x.hasattr(name)? {
+        return x.call_method0(name);
+    }
+    Err(PyErr::new::<PyTypeError, _>(format!(
+        "argument 'x' of '{name}' must be a float or a dual number, got '{}'.",
+        x.get_type().name()?
+    )))
+}
+
+macro_rules! impl_special_function {
+    ($name:ident, $doc:literal) => {
+        #[pyfunction]
+        #[doc = $doc]
+        ///
+        /// Parameters
+        /// ----------
+        /// x : float or dual number
+        ///     The argument of the function.
+        ///
+        /// Returns
+        /// -------
+        /// the function value with the same type as x
+        pub fn $name<'py>(x: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+            special_function(x, stringify!($name), f64::$name)
+        }
+    };
+}
+
+impl_special_function!(
+    bessel_j0,
+    "Calculate the 0th order bessel function of the first kind."
+);
+impl_special_function!(
+    bessel_j1,
+    "Calculate the 1st order bessel function of the first kind."
+);
+impl_special_function!(
+    bessel_j2,
+    "Calculate the 2nd order bessel function of the first kind."
+);
@@ -1,6 +1,9 @@
 #[macro_export]
 macro_rules! impl_dual_num {
     ($py_type_name:ty, $data_type:ty, $field_type:ty) => {
+        impl_dual_num!($py_type_name, $data_type, $field_type, f64);
+    };
+    ($py_type_name:ty, $data_type:ty, $field_type:ty, $float:ty) => {
         impl From<$data_type> for $py_type_name {
             fn from(d: $data_type) -> Self {
                 Self(d)
@@ -13,6 +16,196 @@ macro_rules! impl_dual_num {
             }
         }
 
+        impl $py_type_name {
+            /// Extract a real number from Python floats, integers, and other instances of `numbers.Real`
+            /// (e.g., numpy scalars). Other objects that implement `__float__`, like dual numbers of a
+            /// different type, are rejected instead of silently discarding their derivatives.
+            fn extract_real(obj: &Bound<'_, PyAny>) -> Option<$float> {
+                static REAL: pyo3::sync::PyOnceLock<Py<pyo3::types::PyType>> =
+                    pyo3::sync::PyOnceLock::new();
+                if obj.is_instance_of::<Self>() {
+                    return None;
+                }
+                let is_real = obj.is_instance_of::<pyo3::types::PyFloat>()
+                    || obj.is_instance_of::<pyo3::types::PyInt>()
+                    || REAL
+                        .import(obj.py(), "numbers", "Real")
+                        .and_then(|real| obj.is_instance(real))
+                        .unwrap_or(false);
+                if is_real { obj.extract().ok() } else { None }
+            }
+
+            /// Convert a Python float into the floating point type of the dual number.
+            fn to_float(x: f64) -> $float {
+                <$float as num_traits::FromPrimitive>::from_f64(x).unwrap()
+            }
+
+            /// Convert a dual number of this type or a real number into the Rust data type.
+            fn to_dual(obj: &Bound<'_, PyAny>) -> PyResult<$data_type> {
+                if let Ok(d) = obj.extract::<Self>() {
+                    return Ok(d.0);
+                }
+                if let Some(r) = Self::extract_real(obj) {
+                    return Ok(<$data_type>::from(r));
+                }
+                Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                    "unsupported operand type: '{:?}'. Supported data types are 'float', 'int' and '{}'.",
+                    obj.get_type(),
+                    <Self as pyo3::PyTypeInfo>::NAME
+                )))
+            }
+
+            /// Implementation of numpy's `__array_ufunc__` protocol.
+            ///
+            /// Calls of ufuncs with only scalar inputs (dual numbers of this type and real numbers) are
+            /// evaluated directly. In all other cases, the dual numbers are wrapped in object arrays and the
+            /// ufunc is applied elementwise by numpy, which uses the operators and methods of the dual numbers.
+            fn array_ufunc<'py>(
+                ufunc: &Bound<'py, PyAny>,
+                method: &str,
+                inputs: &Bound<'py, pyo3::types::PyTuple>,
+                kwargs: Option<&Bound<'py, pyo3::types::PyDict>>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                let py = ufunc.py();
+                let scalar = method == "__call__"
+                    && kwargs.is_none_or(|k| k.is_empty())
+                    && inputs
+                        .iter()
+                        .all(|x| x.is_instance_of::<Self>() || Self::extract_real(&x).is_some());
+                if !scalar {
+                    let numpy = py.import("numpy")?;
+                    let inputs = inputs
+                        .iter()
+                        .map(|x| {
+                            if x.is_instance_of::<Self>() {
+                                numpy.call_method1("array", (x, "O"))
+                            } else {
+                                Ok(x)
+                            }
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    return ufunc
+                        .getattr(method)?
+                        .call(pyo3::types::PyTuple::new(py, inputs)?, kwargs);
+                }
+
+                let name: String = ufunc.getattr("__name__")?.extract()?;
+                let x = inputs.get_item(0)?;
+                let y = || inputs.get_item(1);
+                let dual = |i: usize| Self::to_dual(&inputs.get_item(i)?);
+                let boolean = |b: bool| pyo3::types::PyBool::new(py, b).to_owned().into_any();
+                let res = match name.as_str() {
+                    "add" => return x.add(y()?),
+                    "subtract" => return x.sub(y()?),
+                    "multiply" => return x.mul(y()?),
+                    "divide" | "true_divide" => return x.div(y()?),
+                    "power" | "float_power" => return x.pow(y()?, py.None()),
+                    "floor_divide" => return x.floor_div(y()?),
+                    "remainder" | "mod" => return x.rem(y()?),
+                    "negative" => return x.neg(),
+                    "positive" => return x.pos(),
+                    "absolute" | "fabs" => return x.abs(),
+                    "less" => return Ok(boolean(x.lt(y()?)?)),
+                    "less_equal" => return Ok(boolean(x.le(y()?)?)),
+                    "greater" => return Ok(boolean(x.gt(y()?)?)),
+                    "greater_equal" => return Ok(boolean(x.ge(y()?)?)),
+                    "equal" => return Ok(boolean(x.eq(y()?)?)),
+                    "not_equal" => return Ok(boolean(x.ne(y()?)?)),
+                    "isfinite" => return Ok(boolean(dual(0)?.re().is_finite())),
+                    "isinf" => return Ok(boolean(dual(0)?.re().is_infinite())),
+                    "isnan" => return Ok(boolean(dual(0)?.re().is_nan())),
+                    "maximum" | "fmax" => {
+                        let (a, b) = (dual(0)?, dual(1)?);
+                        if a >= b { a } else { b }
+                    }
+                    "minimum" | "fmin" => {
+                        let (a, b) = (dual(0)?, dual(1)?);
+                        if a <= b { a } else { b }
+                    }
+                    "clip" => {
+                        let (a, lo, hi) = (dual(0)?, dual(1)?, dual(2)?);
+                        if a < lo {
+                            lo
+                        } else if a > hi {
+                            hi
+                        } else {
+                            a
+                        }
+                    }
+                    "arctan2" => dual(0)?.atan2(dual(1)?),
+                    "hypot" => {
+                        let (a, b) = (dual(0)?, dual(1)?);
+                        (a.clone() * &a + b.clone() * b).sqrt()
+                    }
+                    "sqrt" => dual(0)?.sqrt(),
+                    "cbrt" => dual(0)?.cbrt(),
+                    "square" => dual(0)?.powi(2),
+                    "reciprocal" => dual(0)?.recip(),
+                    "exp" => dual(0)?.exp(),
+                    "exp2" => dual(0)?.exp2(),
+                    "expm1" => dual(0)?.exp_m1(),
+                    "log" => dual(0)?.ln(),
+                    "log2" => dual(0)?.log2(),
+                    "log10" => dual(0)?.log10(),
+                    "log1p" => dual(0)?.ln_1p(),
+                    "sin" => dual(0)?.sin(),
+                    "cos" => dual(0)?.cos(),
+                    "tan" => dual(0)?.tan(),
+                    "arcsin" => dual(0)?.asin(),
+                    "arccos" => dual(0)?.acos(),
+                    "arctan" => dual(0)?.atan(),
+                    "sinh" => dual(0)?.sinh(),
+                    "cosh" => dual(0)?.cosh(),
+                    "tanh" => dual(0)?.tanh(),
+                    "arcsinh" => dual(0)?.asinh(),
+                    "arccosh" => dual(0)?.acosh(),
+                    "arctanh" => dual(0)?.atanh(),
+                    "deg2rad" | "radians" => dual(0)? * <$float as num_traits::FloatConst>::PI() / 180.0,
+                    "rad2deg" | "degrees" => dual(0)? * 180.0 / <$float as num_traits::FloatConst>::PI(),
+                    "floor" => <$data_type>::from(dual(0)?.re().floor()),
+                    "ceil" => <$data_type>::from(dual(0)?.re().ceil()),
+                    "trunc" => <$data_type>::from(dual(0)?.re().trunc()),
+                    "rint" => <$data_type>::from(dual(0)?.re().round_ties_even()),
+                    "sign" => {
+                        let re = dual(0)?.re();
+                        <$data_type>::from(if re == 0.0 { 0.0 } else { re.signum() })
+                    }
+                    _ => {
+                        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                            "the numpy ufunc '{}' is not supported for '{}'",
+                            name,
+                            <Self as pyo3::PyTypeInfo>::NAME
+                        )));
+                    }
+                };
+                Ok(Bound::new(py, Self::from(res))?.into_any())
+            }
+
+            fn floor_quotient(&self, rhs: &Bound<'_, PyAny>) -> PyResult<($float, $data_type)> {
+                if let Ok(r) = rhs.extract::<Self>() {
+                    return Ok((Self::floor_quotient_of(self.0.re(), r.0.re())?, r.0));
+                }
+                if let Some(r) = Self::extract_real(rhs) {
+                    return Ok((Self::floor_quotient_of(self.0.re(), r)?, <$data_type>::from(r)));
+                }
+                Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                    "unsupported operand types for floor division or modulo: '{}' and '{:?}'. Supported data types are 'float', 'int' and '{}'.",
+                    <Self as pyo3::PyTypeInfo>::NAME,
+                    rhs.get_type(),
+                    <Self as pyo3::PyTypeInfo>::NAME
+                )))
+            }
+
+            fn floor_quotient_of(lhs: $float, rhs: $float) -> PyResult<$float> {
+                if rhs == 0.0 {
+                    return Err(PyErr::new::<pyo3::exceptions::PyZeroDivisionError, _>(
+                        "float floor division by zero",
+                    ));
+                }
+                Ok((lhs / rhs).floor())
+            }
+        }
+
         #[pymethods]
         impl $py_type_name {
             #[staticmethod]
@@ -21,10 +214,31 @@ macro_rules! impl_dual_num {
                 <$data_type>::from_re(re.into()).into()
             }
 
+            #[staticmethod]
+            /// Reconstruct the dual number from its parts (real part and derivatives), e.g., the
+            /// result of `__getstate__`. Used for pickling.
+            pub fn from_state(state: Vec<Vec<$field_type>>) -> PyResult<Self> {
+                let names = <$data_type as $crate::DualParts<$float>>::PART_NAMES;
+                if state.len() != names.len() {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "expected {} parts ({}) to reconstruct '{}', got {}",
+                        names.len(),
+                        names.join(", "),
+                        <Self as pyo3::PyTypeInfo>::NAME,
+                        state.len()
+                    )));
+                }
+                let parts: Vec<Vec<_>> = state
+                    .into_iter()
+                    .map(|p| p.into_iter().map(|x| x.into()).collect())
+                    .collect();
+                Ok(<$data_type as $crate::DualParts<$float>>::from_parts(&parts).into())
+            }
+
             #[getter]
             /// Real part.
             fn get_value(&self) -> $field_type {
-                self.0.re.into()
+                self.0.re.clone().into()
             }
 
             #[inline]
@@ -41,14 +255,20 @@ macro_rules! impl_dual_num {
 
             #[inline]
             /// Power using 64-bin float as exponent.
-            pub fn powf(&self, n: f64) -> Self {
+            pub fn powf(&self, n: $float) -> Self {
                 self.0.powf(n).into()
             }
 
             #[inline]
-            /// Power using self (hyper) dual number as exponent.
-            pub fn powd(&self, n: Self) -> Self {
-                self.0.powd(n.0).into()
+            /// Power using a (hyper) dual number of the same type or a float as exponent.
+            pub fn powd(&self, n: &Bound<'_, PyAny>) -> PyResult<Self> {
+                if let Ok(r) = n.extract::<Self>() {
+                    return Ok(self.0.powd(r.0).into());
+                }
+                if let Some(r) = Self::extract_real(n) {
+                    return Ok(self.0.powf(r).into());
+                }
+                Ok(self.0.powd(Self::to_dual(n)?).into())
             }
 
             #[inline]
@@ -82,15 +302,25 @@ macro_rules! impl_dual_num {
             }
 
             #[inline]
-            /// Calculate natural logarithm.
-            pub fn log(&self) -> Self {
-                self.0.ln().into()
+            /// Calculate natural logarithm or, if provided, logarithm with given base.
+            #[pyo3(signature = (base=None))]
+            pub fn log(&self, base: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                match base {
+                    Some(base) => self.log_base(base),
+                    None => Ok(self.0.ln().into()),
+                }
             }
 
             #[inline]
-            /// Calculate logarithm with given base.
-            pub fn log_base(&self, base: f64) -> Self {
-                self.0.log(base).into()
+            /// Calculate logarithm with given base (float or (hyper) dual number of the same type).
+            pub fn log_base(&self, base: &Bound<'_, PyAny>) -> PyResult<Self> {
+                if let Ok(r) = base.extract::<Self>() {
+                    return Ok((self.0.ln() / r.0.ln()).into());
+                }
+                if let Some(r) = Self::extract_real(base) {
+                    return Ok(self.0.log(r).into());
+                }
+                Ok((self.0.ln() / Self::to_dual(base)?.ln()).into())
             }
 
             #[inline]
@@ -207,24 +437,70 @@ macro_rules! impl_dual_num {
                 self.0.sph_j2().into()
             }
 
+            #[inline]
+            /// Computes the 0th order bessel function of the first kind.
+            pub fn bessel_j0(&self) -> Self {
+                $crate::bessel::bessel_j0(self.0.clone()).into()
+            }
+
+            #[inline]
+            /// Computes the 1st order bessel function of the first kind.
+            pub fn bessel_j1(&self) -> Self {
+                $crate::bessel::bessel_j1(self.0.clone()).into()
+            }
+
+            #[inline]
+            /// Computes the 2nd order bessel function of the first kind.
+            pub fn bessel_j2(&self) -> Self {
+                $crate::bessel::bessel_j2(self.0.clone()).into()
+            }
+
             #[inline]
             /// Fused multiply-add. Computes (self * a) + b with only one rounding error.
-            fn mul_add(&self, a: Self, b: Self) -> Self {
-                self.0.mul_add(a.0, b.0).into()
+            /// `a` and `b` can be floats or (hyper) dual numbers of the same type.
+            fn mul_add(&self, a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(self.0.mul_add(Self::to_dual(a)?, Self::to_dual(b)?).into())
+            }
+
+            /// Computes the four quadrant arctangent of self (y) and other (x) in radians.
+            fn arctan2(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(self.0.atan2(Self::to_dual(other)?).into())
+            }
+
+            /// Computes the four quadrant arctangent of self (y) and other (x) in radians.
+            fn atan2(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+                self.arctan2(other)
+            }
+
+            /// Calculate the length of the hypotenuse of a right-angle triangle given legs of length self and other.
+            fn hypot(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+                let other = Self::to_dual(other)?;
+                Ok((self.0.clone() * &self.0 + other.clone() * other).sqrt().into())
+            }
+
+            #[pyo3(signature = (ufunc, method, *inputs, **kwargs))]
+            fn __array_ufunc__<'py>(
+                &self,
+                ufunc: &Bound<'py, PyAny>,
+                method: &str,
+                inputs: &Bound<'py, pyo3::types::PyTuple>,
+                kwargs: Option<&Bound<'py, pyo3::types::PyDict>>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                Self::array_ufunc(ufunc, method, inputs, kwargs)
             }
 
             fn __add__<'py>(&self, rhs: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
-                if let Ok(r) = rhs.extract::<f64>() {
-                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() + r))?.into_any());
-                };
                 if let Ok(r) = rhs.extract::<Self>() {
                     return Ok(Bound::new(rhs.py(), Self(self.0.clone() + r.0))?.into_any());
                 };
+                if let Some(r) = Self::extract_real(rhs) {
+                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() + r))?.into_any());
+                };
                 if let Ok(r) = rhs.extract::<PyReadonlyArrayDyn<f64>>() {
                     return Ok(PyArray::from_owned_object_array(
                         rhs.py(),
                         r.as_array()
-                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() + ri)).unwrap()),
+                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() + Self::to_float(ri))).unwrap()),
                     )
                     .into_any());
                 }
@@ -243,34 +519,34 @@ macro_rules! impl_dual_num {
                     } else {
                         return Err(PyErr::new::<PyTypeError, _>(format!(
                             "Operation with the provided object type is not implemented. Supported data types are 'float', 'int' and '{}'.",
-                            stringify!($py_type_name)
+                            <Self as pyo3::PyTypeInfo>::NAME
                         )));
                     }
                 }
 
                 Err(PyErr::new::<PyTypeError, _>(format!(
                     "Addition of \nleft:  {}\nright: {:?}\nis not implemented!",
-                    stringify!($py_type_name),
+                    <Self as pyo3::PyTypeInfo>::NAME,
                     rhs.get_type()
                 )))
             }
 
-            fn __radd__(&self, lhs: f64) -> Self {
+            fn __radd__(&self, lhs: $float) -> Self {
                 (self.0.clone() + lhs).into()
             }
 
             fn __sub__<'py>(&self, rhs: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
-                if let Ok(r) = rhs.extract::<f64>() {
-                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() - r))?.into_any());
-                };
                 if let Ok(r) = rhs.extract::<Self>() {
                     return Ok(Bound::new(rhs.py(), Self(self.0.clone() - r.0))?.into_any());
                 };
+                if let Some(r) = Self::extract_real(rhs) {
+                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() - r))?.into_any());
+                };
                 if let Ok(r) = rhs.extract::<PyReadonlyArrayDyn<f64>>() {
                     return Ok(PyArray::from_owned_object_array(
                         rhs.py(),
                         r.as_array()
-                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() - ri)).unwrap()),
+                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() - Self::to_float(ri))).unwrap()),
                     )
                     .into_any());
                 }
@@ -289,34 +565,34 @@ macro_rules! impl_dual_num {
                     } else {
                         return Err(PyErr::new::<PyTypeError, _>(format!(
                             "Operation with the provided object type is not implemented. Supported data types are 'float', 'int' and '{}'.",
-                            stringify!($py_type_name)
+                            <Self as pyo3::PyTypeInfo>::NAME
                         )));
                     }
                 }
 
                 Err(PyErr::new::<PyTypeError, _>(format!(
                     "Subtraction of \nleft:  {}\nright: {:?}\nis not implemented!",
-                    stringify!($py_type_name),
+                    <Self as pyo3::PyTypeInfo>::NAME,
                     rhs.get_type()
                 )))
             }
 
-            fn __rsub__(&self, lhs: f64) -> Self {
+            fn __rsub__(&self, lhs: $float) -> Self {
                 (-self.0.clone() + lhs).into()
             }
 
             fn __mul__<'py>(&self, rhs: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
-                if let Ok(r) = rhs.extract::<f64>() {
-                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() * r))?.into_any());
-                };
                 if let Ok(r) = rhs.extract::<Self>() {
                     return Ok(Bound::new(rhs.py(), Self(self.0.clone() * r.0))?.into_any());
                 };
+                if let Some(r) = Self::extract_real(rhs) {
+                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() * r))?.into_any());
+                };
                 if let Ok(r) = rhs.extract::<PyReadonlyArrayDyn<f64>>() {
                     return Ok(PyArray::from_owned_object_array(
                         rhs.py(),
                         r.as_array()
-                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() * ri)).unwrap()),
+                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() * Self::to_float(ri))).unwrap()),
                     )
                     .into_any());
                 }
@@ -335,34 +611,34 @@ macro_rules! impl_dual_num {
                     } else {
                         return Err(PyErr::new::<PyTypeError, _>(format!(
                             "Operation with the provided object type is not implemented. Supported data types are 'float', 'int' and '{}'.",
-                            stringify!($py_type_name)
+                            <Self as pyo3::PyTypeInfo>::NAME
                         )));
                     }
                 }
 
                 Err(PyErr::new::<PyTypeError, _>(format!(
                     "Multiplication of \nleft:  {}\nright: {:?}\nis not implemented!",
-                    stringify!($py_type_name),
+                    <Self as pyo3::PyTypeInfo>::NAME,
                     rhs.get_type()
                 )))
             }
 
-            fn __rmul__(&self, lhs: f64) -> Self {
+            fn __rmul__(&self, lhs: $float) -> Self {
                 (self.0.clone() * lhs).into()
             }
 
             fn __truediv__<'py>(&self, rhs: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
-                if let Ok(r) = rhs.extract::<f64>() {
-                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() / r))?.into_any());
-                };
                 if let Ok(r) = rhs.extract::<Self>() {
                     return Ok(Bound::new(rhs.py(), Self(self.0.clone() / r.0))?.into_any());
                 };
+                if let Some(r) = Self::extract_real(rhs) {
+                    return Ok(Bound::new(rhs.py(), Self(self.0.clone() / r))?.into_any());
+                };
                 if let Ok(r) = rhs.extract::<PyReadonlyArrayDyn<f64>>() {
                     return Ok(PyArray::from_owned_object_array(
                         rhs.py(),
                         r.as_array()
-                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() / ri)).unwrap()),
+                            .mapv(|ri| Py::new(rhs.py(), Self(self.0.clone() / Self::to_float(ri))).unwrap()),
                     )
                     .into_any());
                 }
@@ -381,32 +657,32 @@ macro_rules! impl_dual_num {
                     } else {
                         return Err(PyErr::new::<PyTypeError, _>(format!(
                             "Operation with the provided object type is not implemented. Supported data types are 'float', 'int' and '{}'.",
-                            stringify!($py_type_name)
+                            <Self as pyo3::PyTypeInfo>::NAME
                         )));
                     }
                 }
 
                 Err(PyErr::new::<PyTypeError, _>(format!(
                     "Division of \nleft:  {}\nright: {:?}\nis not implemented!",
-                    stringify!($py_type_name),
+                    <Self as pyo3::PyTypeInfo>::NAME,
                     rhs.get_type()
                 )))
             }
 
-            fn __rtruediv__(&self, lhs: f64) -> Self {
+            fn __rtruediv__(&self, lhs: $float) -> Self {
                 (self.0.recip() * lhs).into()
             }
 
             fn __pow__(&self, rhs: &Bound<'_, PyAny>, _mod: Option<u32>) -> PyResult<Self> {
+                if let Ok(r) = rhs.extract::<Self>() {
+                    return Ok(self.0.powd(r.0).into());
+                };
                 if let Ok(r) = rhs.extract::<i32>() {
                     return Ok(self.0.powi(r).into());
                 };
-                if let Ok(r) = rhs.extract::<f64>() {
+                if let Some(r) = Self::extract_real(rhs) {
                     return Ok(self.0.powf(r).into());
                 };
-                if let Ok(r) = rhs.extract::<Self>() {
-                    return Ok(self.0.powd(r.0).into());
-                };
                 Err(PyErr::new::<PyTypeError, _>(format!("not implemented!")))
             }
 
@@ -417,16 +693,7 @@ macro_rules! impl_dual_num {
             ) -> PyResult<bool> {
                 use pyo3::class::basic::CompareOp;
 
-                if let Ok(r) = rhs.extract::<f64>() {
-                    match op {
-                        CompareOp::Lt => Ok(self.0 < r),
-                        CompareOp::Le => Ok(self.0 <= r),
-                        CompareOp::Eq => Ok(self.0 == r),
-                        CompareOp::Ne => Ok(self.0 != r),
-                        CompareOp::Gt => Ok(self.0 > r),
-                        CompareOp::Ge => Ok(self.0 >= r),
-                    }
-                } else if let Ok(r) = rhs.extract::<Self>() {
+                if let Ok(r) = rhs.extract::<Self>() {
                     match op {
                         CompareOp::Lt => Ok(self.0 < r.0),
                         CompareOp::Le => Ok(self.0 <= r.0),
@@ -435,6 +702,15 @@ macro_rules! impl_dual_num {
                         CompareOp::Gt => Ok(self.0 > r.0),
                         CompareOp::Ge => Ok(self.0 >= r.0),
                     }
+                } else if let Some(r) = Self::extract_real(rhs) {
+                    match op {
+                        CompareOp::Lt => Ok(self.0 < r),
+                        CompareOp::Le => Ok(self.0 <= r),
+                        CompareOp::Eq => Ok(self.0 == r),
+                        CompareOp::Ne => Ok(self.0 != r),
+                        CompareOp::Gt => Ok(self.0 > r),
+                        CompareOp::Ge => Ok(self.0 >= r),
+                    }
                 } else {
                     match op {
                         CompareOp::Eq => Ok(false),
@@ -442,9 +718,9 @@ macro_rules! impl_dual_num {
                         _ =>
                         Err(PyErr::new::<PyTypeError, _>(format!(
                             "unsupported operand types for comparison: '{}' and unsupported type '{:?}'. Supported data types are 'float' and '{}'.",
-                            stringify!($py_type_name),
+                            <Self as pyo3::PyTypeInfo>::NAME,
                             rhs.get_type(),
-                            stringify!($py_type_name)
+                            <Self as pyo3::PyTypeInfo>::NAME
                         ))),
                     }
                 }
@@ -454,9 +730,146 @@ macro_rules! impl_dual_num {
                 Ok((-self.0.clone()).into())
             }
 
+            fn __pos__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __abs__(&self) -> Self {
+                if self.0.re() < 0.0 {
+                    (-self.0.clone()).into()
+                } else {
+                    self.clone()
+                }
+            }
+
+            /// Power with a real base. For non-positive bases, the real part of the exponent has to be
+            /// an integer and the derivatives of the result vanish.
+            fn __rpow__(&self, lhs: $float, _mod: Option<u32>) -> PyResult<Self> {
+                if lhs > 0.0 {
+                    return Ok((self.0.clone() * lhs.ln()).exp().into());
+                }
+                let n = self.0.re();
+                if n.fract() == 0.0 && n.abs() <= i32::MAX as $float {
+                    return Ok(<$data_type>::from(lhs.powi(n as i32)).into());
+                }
+                Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "the base {lhs} of a power with the non-integer exponent {n} must be positive."
+                )))
+            }
+
+            /// Quotient of the real parts rounded towards negative infinity. The derivatives vanish.
+            fn __floordiv__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<Self> {
+                let (q, _) = self.floor_quotient(rhs)?;
+                Ok(<$data_type>::from(q).into())
+            }
+
+            fn __rfloordiv__(&self, lhs: $float) -> PyResult<Self> {
+                let q = Self::floor_quotient_of(lhs, self.0.re())?;
+                Ok(<$data_type>::from(q).into())
+            }
+
+            /// Remainder `self - rhs * (self // rhs)` with the sign of `rhs`, like for Python floats.
+            fn __mod__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<Self> {
+                let (q, r) = self.floor_quotient(rhs)?;
+                Ok((self.0.clone() - r * q).into())
+            }
+
+            fn __rmod__(&self, lhs: $float) -> PyResult<Self> {
+                let q = Self::floor_quotient_of(lhs, self.0.re())?;
+                Ok((-self.0.clone() * q + lhs).into())
+            }
+
+            fn __divmod__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<(Self, Self)> {
+                let (q, r) = self.floor_quotient(rhs)?;
+                Ok((<$data_type>::from(q).into(), (self.0.clone() - r * q).into()))
+            }
+
+            fn __rdivmod__(&self, lhs: $float) -> PyResult<(Self, Self)> {
+                let q = Self::floor_quotient_of(lhs, self.0.re())?;
+                Ok((<$data_type>::from(q).into(), (-self.0.clone() * q + lhs).into()))
+            }
+
+            /// Real part as float. All derivatives are discarded.
+            fn __float__(&self) -> f64 {
+                self.0.re().into()
+            }
+
+            /// Real part truncated to an integer.
+            fn __int__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                pyo3::types::PyFloat::new(py, self.0.re().into()).call_method0("__int__")
+            }
+
+            fn __trunc__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                pyo3::types::PyFloat::new(py, self.0.re().into()).call_method0("__trunc__")
+            }
+
+            fn __floor__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                pyo3::types::PyFloat::new(py, self.0.re().into()).call_method0("__floor__")
+            }
+
+            fn __ceil__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                pyo3::types::PyFloat::new(py, self.0.re().into()).call_method0("__ceil__")
+            }
+
+            /// Round the real part like a Python float. Without `ndigits` an integer is returned,
+            /// otherwise a dual number with vanishing derivatives.
+            #[pyo3(signature = (ndigits=None))]
+            fn __round__<'py>(
+                &self,
+                py: Python<'py>,
+                ndigits: Option<i32>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                let re = pyo3::types::PyFloat::new(py, self.0.re().into());
+                match ndigits {
+                    None => re.call_method0("__round__"),
+                    Some(n) => {
+                        let r: $float = re.call_method1("__round__", (n,))?.extract()?;
+                        Ok(Bound::new(py, Self::from(<$data_type>::from(r)))?.into_any())
+                    }
+                }
+            }
+
+            fn __bool__(&self) -> bool {
+                self.0.re() != 0.0
+            }
+
             fn __repr__(&self) -> PyResult<String> {
                 Ok(self.0.to_string())
             }
+
+            /// Parts (real part and derivatives) of the dual number.
+            fn __getstate__(&self) -> Vec<Vec<$field_type>> {
+                $crate::DualParts::to_parts(&self.0)
+                    .into_iter()
+                    .map(|p| p.into_iter().map(|x| x.into()).collect())
+                    .collect()
+            }
+
+            fn __setstate__(&mut self, state: Vec<Vec<$field_type>>) -> PyResult<()> {
+                *self = Self::from_state(state)?;
+                Ok(())
+            }
+
+            fn __reduce__<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<(Bound<'py, PyAny>, (Vec<Vec<$field_type>>,))> {
+                let from_state = py.get_type::<Self>().getattr("from_state")?;
+                Ok((from_state, (self.__getstate__(),)))
+            }
+
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+                self.clone()
+            }
+
+            /// Hash of the real part, consistent with the comparison operators that only consider the real part.
+            fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+                pyo3::types::PyFloat::new(py, self.0.re().into()).hash()
+            }
         }
     };
 }
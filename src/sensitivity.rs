@@ -0,0 +1,215 @@
+//! Local sensitivity analysis of models with named parameters.
+//!
+//! The parameters are passed to the model as a [`HashMap`] from their names to their values. [`sensitivity`]
+//! calculates the Jacobian of the outputs with respect to all parameters (using [`Gradients::jacobian`]) and
+//! [`sensitivity_second_order`] additionally calculates the Hessians of all outputs. The results are collected in
+//! a [`SensitivityReport`] that contains absolute, relative, and log-log sensitivities and ranks the parameters
+//! by their importance for every output.
+//! ```
+//! # use num_dual::sensitivity::sensitivity;
+//! # use num_dual::DualNum;
+//! # use nalgebra::{DVector, dvector};
+//! # use std::collections::HashMap;
+//! # use approx::assert_relative_eq;
+//! // ideal gas pressure and density
+//! fn ideal_gas<D: DualNum<f64> + Copy>(p: &HashMap<String, D>) -> DVector<D> {
+//!     let (n, t, v) = (p["moles"], p["temperature"], p["volume"]);
+//!     dvector![n * t * 8.314 / v, n / v]
+//! }
+//!
+//! let parameters = HashMap::from([
+//!     ("moles".to_string(), 2.0),
+//!     ("temperature".to_string(), 300.0),
+//!     ("volume".to_string(), 0.05),
+//! ]);
+//! let report = sensitivity(ideal_gas, &parameters);
+//! assert_eq!(report.parameters, ["moles", "temperature", "volume"]);
+//! assert_relative_eq!(report.log_log.row(0).transpose(), dvector![1.0, 1.0, -1.0]);
+//! assert_eq!(report.ranking(1)[0].0, "moles");
+//! println!("{report}");
+//! ```
+use crate::{Dual, DualNum, DualNumFloat, Gradients, HyperDual};
+use nalgebra::{DMatrix, DVector, Dyn, Scalar};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Local sensitivities of the outputs of a model with respect to its named parameters.
+///
+/// For an output `f` and a parameter `p`, the sensitivities are
+/// - absolute: `∂f/∂p`,
+/// - relative: `∂f/∂ln p = p ∂f/∂p`, i.e., the change of `f` for a relative change of `p`,
+/// - log-log: `∂ln f/∂ln p = p/f ∂f/∂p`, i.e., the dimensionless elasticity of `f` with respect to `p`.
+///
+/// Rows of the matrices correspond to the outputs and columns to the parameters. Log-log sensitivities
+/// of outputs that vanish are not finite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SensitivityReport<F> {
+    /// Names of the parameters in alphabetical order.
+    pub parameters: Vec<String>,
+    /// Values of the parameters.
+    pub values: DVector<F>,
+    /// Values of the outputs.
+    pub outputs: DVector<F>,
+    /// Absolute sensitivities `∂f/∂p`.
+    pub absolute: DMatrix<F>,
+    /// Relative sensitivities `p ∂f/∂p`.
+    pub relative: DMatrix<F>,
+    /// Log-log sensitivities (elasticities) `p/f ∂f/∂p`.
+    pub log_log: DMatrix<F>,
+    /// Hessians `∂²f/∂p∂q` of all outputs, if calculated with [`sensitivity_second_order`].
+    pub interactions: Option<Vec<DMatrix<F>>>,
+}
+
+/// Calculate the first-order sensitivities of the outputs of `g` with respect to the named `parameters`.
+///
+/// The model is evaluated once for every parameter.
+pub fn sensitivity<G, F: DualNum<F> + DualNumFloat>(
+    g: G,
+    parameters: &HashMap<String, F>,
+) -> SensitivityReport<F>
+where
+    G: Fn(&HashMap<String, Dual<F, F>>) -> DVector<Dual<F, F>>,
+{
+    let (names, values) = sorted(parameters);
+    let (outputs, absolute) = Dyn::jacobian(
+        |x: DVector<Dual<F, F>>, _: &()| g(&named(&names, x)),
+        &values,
+        &(),
+    );
+    SensitivityReport::new(names, values, outputs, absolute, None)
+}
+
+/// Calculate the first-order sensitivities and the second-order interactions of the outputs of `g`
+/// with respect to the named `parameters`.
+///
+/// The model is evaluated once for every pair of parameters (including the diagonal), and all outputs
+/// are differentiated in the same evaluation.
+pub fn sensitivity_second_order<G, F: DualNum<F> + DualNumFloat>(
+    g: G,
+    parameters: &HashMap<String, F>,
+) -> SensitivityReport<F>
+where
+    G: Fn(&HashMap<String, HyperDual<F, F>>) -> DVector<HyperDual<F, F>>,
+{
+    let (names, values) = sorted(parameters);
+    let n = values.len();
+    let x = values.map(HyperDual::from_re);
+    let outputs = g(&named(&names, x.clone())).map(|r| r.re);
+    let m = outputs.len();
+    let mut absolute = DMatrix::zeros(m, n);
+    let mut hessians = vec![DMatrix::zeros(n, n); m];
+    for j in 0..n {
+        for k in j..n {
+            let mut x = x.clone();
+            x[j].eps1 = F::one();
+            x[k].eps2 = F::one();
+            let res = g(&named(&names, x));
+            for (i, (r, h)) in res.iter().zip(hessians.iter_mut()).enumerate() {
+                h[(j, k)] = r.eps1eps2;
+                h[(k, j)] = r.eps1eps2;
+                if j == k {
+                    absolute[(i, j)] = r.eps1;
+                }
+            }
+        }
+    }
+    SensitivityReport::new(names, values, outputs, absolute, Some(hessians))
+}
+
+impl<F: DualNum<F> + DualNumFloat> SensitivityReport<F> {
+    fn new(
+        parameters: Vec<String>,
+        values: DVector<F>,
+        outputs: DVector<F>,
+        absolute: DMatrix<F>,
+        interactions: Option<Vec<DMatrix<F>>>,
+    ) -> Self {
+        let relative = DMatrix::from_fn(absolute.nrows(), absolute.ncols(), |i, j| {
+            absolute[(i, j)] * values[j]
+        });
+        let log_log = DMatrix::from_fn(absolute.nrows(), absolute.ncols(), |i, j| {
+            relative[(i, j)] / outputs[i]
+        });
+        Self {
+            parameters,
+            values,
+            outputs,
+            absolute,
+            relative,
+            log_log,
+            interactions,
+        }
+    }
+
+    /// Names of the parameters and their log-log sensitivities for the given output, sorted by
+    /// decreasing magnitude.
+    pub fn ranking(&self, output: usize) -> Vec<(&str, F)> {
+        let mut ranking: Vec<_> = self
+            .parameters
+            .iter()
+            .zip(self.log_log.row(output).iter())
+            .map(|(p, &s)| (p.as_str(), s))
+            .collect();
+        ranking.sort_by(|(_, a), (_, b)| b.abs().partial_cmp(&a.abs()).unwrap_or(Ordering::Equal));
+        ranking
+    }
+
+    /// Normalized second-order interactions `p q/f ∂²f/∂p∂q` of the given output, if the
+    /// Hessians were calculated.
+    pub fn normalized_interactions(&self, output: usize) -> Option<DMatrix<F>> {
+        self.interactions.as_ref().map(|h| {
+            let h = &h[output];
+            DMatrix::from_fn(h.nrows(), h.ncols(), |j, k| {
+                h[(j, k)] * self.values[j] * self.values[k] / self.outputs[output]
+            })
+        })
+    }
+}
+
+impl<F: DualNum<F> + DualNumFloat> fmt::Display for SensitivityReport<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .parameters
+            .iter()
+            .map(|p| p.len())
+            .max()
+            .unwrap_or(0)
+            .max(9);
+        for (i, output) in self.outputs.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "output {i}: {output}")?;
+            writeln!(
+                f,
+                "{:<width$} {:>14} {:>14} {:>14} {:>14}",
+                "parameter", "value", "absolute", "relative", "log-log"
+            )?;
+            for (name, _) in self.ranking(i) {
+                let j = self.parameters.iter().position(|p| p == name).unwrap();
+                writeln!(
+                    f,
+                    "{:<width$} {:>14.6} {:>14.6} {:>14.6} {:>14.6}",
+                    name,
+                    self.values[j],
+                    self.absolute[(i, j)],
+                    self.relative[(i, j)],
+                    self.log_log[(i, j)]
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn sorted<F: Scalar + Copy>(parameters: &HashMap<String, F>) -> (Vec<String>, DVector<F>) {
+    let mut names: Vec<_> = parameters.keys().cloned().collect();
+    names.sort();
+    let values = DVector::from_iterator(names.len(), names.iter().map(|p| parameters[p]));
+    (names, values)
+}
+
+fn named<D: Clone>(names: &[String], x: DVector<D>) -> HashMap<String, D> {
+    names.iter().cloned().zip(x.iter().cloned()).collect()
+}
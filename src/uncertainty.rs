@@ -0,0 +1,162 @@
+//! Propagation of uncertainties through functions that are generic over the dual number type.
+//!
+//! An [`Uncertain`] input consists of the mean and the covariance matrix of `N` (correlated) variables.
+//! [`Uncertain::propagate`] linearizes a function around the mean using a single evaluation with
+//! [`DualSVec`] and returns the mean and the covariance `J Σ Jᵀ` of the outputs. [`Uncertain::propagate_second_order`]
+//! additionally uses the Hessians of the outputs, calculated in a single evaluation with [`Dual2SVec`], to
+//! correct the mean (and the covariance) for the curvature of the function.
+//! ```
+//! # use num_dual::uncertainty::Uncertain;
+//! # use nalgebra::vector;
+//! # use approx::assert_relative_eq;
+//! // area and circumference of a rectangle with measured side lengths
+//! let sides = Uncertain::independent(vector![3.0, 2.0], vector![0.1, 0.2]);
+//! let res = sides.propagate(|x| vector![x[0] * x[1], (x[0] + x[1]) * 2.0]);
+//! assert_relative_eq!(res.mean, vector![6.0, 10.0]);
+//! assert_relative_eq!(res.std_dev()[0], (0.04f64 + 0.36).sqrt());
+//! assert_relative_eq!(res.std_dev()[1], (0.04f64 + 0.16).sqrt());
+//! assert_relative_eq!(res.covariance[(0, 1)], 2.0 * 3.0 * 0.04 + 2.0 * 2.0 * 0.01);
+//! ```
+use crate::{Derivative, Dual2SVec, DualNum, DualNumFloat, DualSVec};
+use nalgebra::allocator::Allocator;
+use nalgebra::{Const, DefaultAllocator, Dim, OMatrix, OVector, SMatrix, SVector, Scalar, U1};
+
+/// Input variables with a known mean and covariance matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Uncertain<F: Scalar, const N: usize> {
+    /// Mean values of the variables.
+    pub mean: SVector<F, N>,
+    /// Covariance matrix of the variables.
+    pub covariance: SMatrix<F, N, N>,
+}
+
+/// Means and covariance matrix of the outputs of a function with uncertain inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Propagated<F: Scalar, M: Dim>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, M>,
+{
+    /// Mean values of the outputs.
+    pub mean: OVector<F, M>,
+    /// Covariance matrix of the outputs.
+    pub covariance: OMatrix<F, M, M>,
+}
+
+impl<F: DualNum<F> + DualNumFloat, const N: usize> Uncertain<F, N> {
+    /// Create uncertain variables from their mean and covariance matrix.
+    pub fn new(mean: SVector<F, N>, covariance: SMatrix<F, N, N>) -> Self {
+        Self { mean, covariance }
+    }
+
+    /// Create independent uncertain variables from their mean and standard deviations.
+    pub fn independent(mean: SVector<F, N>, std_dev: SVector<F, N>) -> Self {
+        Self::new(
+            mean,
+            SMatrix::from_diagonal(&std_dev.component_mul(&std_dev)),
+        )
+    }
+
+    /// Standard deviations of the variables.
+    pub fn std_dev(&self) -> SVector<F, N> {
+        self.covariance.diagonal().map(|v| v.sqrt())
+    }
+
+    /// Correlation matrix of the variables.
+    pub fn correlation(&self) -> SMatrix<F, N, N> {
+        correlation(&self.covariance)
+    }
+
+    /// Propagate the uncertainty through the (vector) function `g` using a linearization at the mean.
+    ///
+    /// The outputs are evaluated at the mean and their covariance is `J Σ Jᵀ`, with the Jacobian `J`
+    /// of `g` and the covariance `Σ` of the inputs.
+    pub fn propagate<G, M: Dim>(&self, g: G) -> Propagated<F, M>
+    where
+        G: FnOnce(SVector<DualSVec<F, F, N>, N>) -> OVector<DualSVec<F, F, N>, M>,
+        DefaultAllocator:
+            Allocator<M> + Allocator<M, M> + Allocator<M, Const<N>> + Allocator<Const<N>, M>,
+    {
+        let mut x = self.mean.map(DualSVec::from_re);
+        for (i, xi) in x.iter_mut().enumerate() {
+            xi.eps = Derivative::derivative_generic(Const, U1, i);
+        }
+        let res = g(x);
+        let (m, _) = res.shape_generic();
+        let mean = res.map(|r| r.re);
+        let jac = OMatrix::from_fn_generic(m, Const::<N>, |i, j| {
+            res[i].eps.unwrap_generic(Const, U1)[j]
+        });
+        let covariance = &jac * self.covariance * jac.transpose();
+        Propagated { mean, covariance }
+    }
+
+    /// Propagate the uncertainty through the (vector) function `g` using a second-order expansion around the mean.
+    ///
+    /// With the gradients `Jᵢ` and Hessians `Hᵢ` of the outputs, the means are corrected by the bias
+    /// `½ tr(Hᵢ Σ)` and the covariances are `Jᵢ Σ Jⱼᵀ + ½ tr(Hᵢ Σ Hⱼ Σ)`. The correction of the covariance
+    /// assumes that the inputs are normally distributed. Both results are exact for quadratic functions.
+    pub fn propagate_second_order<G, M: Dim>(&self, g: G) -> Propagated<F, M>
+    where
+        G: FnOnce(SVector<Dual2SVec<F, F, N>, N>) -> OVector<Dual2SVec<F, F, N>, M>,
+        DefaultAllocator:
+            Allocator<M> + Allocator<M, M> + Allocator<M, Const<N>> + Allocator<Const<N>, M>,
+    {
+        let mut x = self.mean.map(Dual2SVec::from_re);
+        for (i, xi) in x.iter_mut().enumerate() {
+            xi.v1 = Derivative::derivative_generic(U1, Const, i);
+        }
+        let res = g(x);
+        let (m, _) = res.shape_generic();
+        let jac =
+            OMatrix::from_fn_generic(m, Const::<N>, |i, j| res[i].v1.unwrap_generic(U1, Const)[j]);
+        let h_sigma: Vec<SMatrix<F, N, N>> = res
+            .iter()
+            .map(|r| r.v2.unwrap_generic(Const, Const) * self.covariance)
+            .collect();
+        let half = F::from_f64(0.5).unwrap();
+        let mean = OVector::from_fn_generic(m, U1, |i, _| res[i].re + h_sigma[i].trace() * half);
+        let mut covariance = &jac * self.covariance * jac.transpose();
+        for (i, hi) in h_sigma.iter().enumerate() {
+            for (j, hj) in h_sigma.iter().enumerate() {
+                covariance[(i, j)] += (hi * hj).trace() * half;
+            }
+        }
+        Propagated { mean, covariance }
+    }
+}
+
+impl<F: DualNum<F> + DualNumFloat, M: Dim> Propagated<F, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, M>,
+{
+    /// Standard deviations of the outputs.
+    pub fn std_dev(&self) -> OVector<F, M> {
+        self.covariance.diagonal().map(|v| v.sqrt())
+    }
+
+    /// Correlation matrix of the outputs.
+    pub fn correlation(&self) -> OMatrix<F, M, M> {
+        correlation(&self.covariance)
+    }
+}
+
+/// Correlation matrix from a covariance matrix. Entries that correspond to variables without
+/// variance are set to zero (one on the diagonal).
+fn correlation<F: DualNum<F> + DualNumFloat, M: Dim>(
+    covariance: &OMatrix<F, M, M>,
+) -> OMatrix<F, M, M>
+where
+    DefaultAllocator: Allocator<M, M>,
+{
+    let (m, _) = covariance.shape_generic();
+    OMatrix::from_fn_generic(m, m, |i, j| {
+        let s = (covariance[(i, i)] * covariance[(j, j)]).sqrt();
+        if i == j {
+            F::one()
+        } else if s > F::zero() {
+            covariance[(i, j)] / s
+        } else {
+            F::zero()
+        }
+    })
+}
@@ -0,0 +1,46 @@
+"""Tests of the Python operators of dual numbers. Run with `python -m unittest discover tests/python`."""
+
+import math
+import unittest
+
+from num_dual import Dual64, Dual2_64, HyperDual64
+
+
+class TestOperators(unittest.TestCase):
+    def test_dual_with_real(self):
+        x = Dual64(2.0, 1.0)
+        for y in (x + 1, x + 1.0, 1.0 + x):
+            self.assertEqual(y.value, 3.0)
+            self.assertEqual(y.first_derivative, 1.0)
+        self.assertEqual((x * x).first_derivative, 4.0)
+        self.assertTrue(x < 3)
+        self.assertTrue(x == Dual64(2.0, 1.0))
+
+    def test_dual_with_other_dual_type(self):
+        with self.assertRaises(TypeError):
+            Dual64(2.0, 1.0) + HyperDual64(1.0, 1.0, 0.0, 0.0)
+
+    def test_rpow_positive_base(self):
+        y = 2.0 ** Dual64(3.0, 1.0)
+        self.assertAlmostEqual(y.value, 8.0)
+        self.assertAlmostEqual(y.first_derivative, 8.0 * math.log(2.0))
+
+    def test_rpow_non_positive_base_integer_exponent(self):
+        y = (-2.0) ** Dual64(3.0, 1.0)
+        self.assertEqual(y.value, -8.0)
+        self.assertEqual(y.first_derivative, 0.0)
+        y = 0.0 ** Dual2_64(2.0, 1.0, 0.0)
+        self.assertEqual(y.value, 0.0)
+        self.assertEqual(y.first_derivative, 0.0)
+        self.assertEqual(y.second_derivative, 0.0)
+        self.assertEqual((0.0 ** Dual64(0.0, 1.0)).value, 1.0)
+
+    def test_rpow_non_positive_base_non_integer_exponent(self):
+        with self.assertRaises(ValueError):
+            (-2.0) ** Dual64(0.5, 1.0)
+        with self.assertRaises(ValueError):
+            0.0 ** Dual64(0.5, 1.0)
+
+
+if __name__ == "__main__":
+    unittest.main()
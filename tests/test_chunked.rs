@@ -0,0 +1,77 @@
+use approx::assert_relative_eq;
+use nalgebra::{DVector, DefaultAllocator, Dim, Dyn, OVector, U2, allocator::Allocator, dvector};
+use num_dual::*;
+
+fn rosenbrock<D: DualNum<f64> + Copy, N: Dim>(x: OVector<D, N>, a: &D) -> D
+where
+    DefaultAllocator: Allocator<N>,
+{
+    (0..x.len() - 1)
+        .map(|i| (*a - x[i]).powi(2) + (x[i + 1] - x[i].powi(2)).powi(2) * 100.0)
+        .sum()
+}
+
+fn residuals<D: DualNum<f64> + Copy>(x: DVector<D>, _: &()) -> OVector<D, U2> {
+    let s = x.iter().fold(D::zero(), |acc, &xi| acc + xi);
+    let p = x
+        .iter()
+        .enumerate()
+        .fold(D::zero(), |acc, (i, &xi)| acc + xi.sin() * (i as f64));
+    OVector::<D, U2>::new(s.powi(2), p.exp())
+}
+
+#[test]
+fn test_chunked_gradient() {
+    let x = dvector![0.3, -1.2, 0.8, 2.0, 0.1, -0.5, 1.4];
+    let (f_ref, g_ref) = Dyn::gradient(rosenbrock, &x, &1.5);
+    for (f, g) in [
+        Chunked::<1>::gradient(rosenbrock, &x, &1.5),
+        Chunked::<3>::gradient(rosenbrock, &x, &1.5),
+        Chunked::<7>::gradient(rosenbrock, &x, &1.5),
+        <Chunked>::gradient(rosenbrock, &x, &1.5),
+    ] {
+        assert_relative_eq!(f, f_ref);
+        assert_relative_eq!(g, g_ref, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_chunked_hessian() {
+    let x = dvector![0.3, -1.2, 0.8, 2.0, 0.1, -0.5, 1.4];
+    let (f_ref, g_ref, h_ref) = Dyn::hessian(rosenbrock, &x, &1.5);
+    for (f, g, h) in [
+        Chunked::<1>::hessian(rosenbrock, &x, &1.5),
+        Chunked::<3>::hessian(rosenbrock, &x, &1.5),
+        Chunked::<7>::hessian(rosenbrock, &x, &1.5),
+        <Chunked>::hessian(rosenbrock, &x, &1.5),
+    ] {
+        assert_relative_eq!(f, f_ref);
+        assert_relative_eq!(g, g_ref, max_relative = 1e-14);
+        assert_relative_eq!(h, h_ref, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_chunked_jacobian() {
+    let x = dvector![0.3, -1.2, 0.8, 2.0, 0.1];
+    let (f_ref, j_ref) = Dyn::jacobian(residuals, &x, &());
+    for (f, j) in [
+        Chunked::<2>::jacobian(residuals, &x, &()),
+        <Chunked>::jacobian(residuals, &x, &()),
+    ] {
+        assert_relative_eq!(f, f_ref);
+        assert_eq!(j.shape(), (2, 5));
+        assert_relative_eq!(j, j_ref, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_chunked_empty() {
+    let x = DVector::zeros(0);
+    let (f, g) = <Chunked>::gradient(|_, a: &DualSVec64<8>| *a, &x, &2.0);
+    assert_eq!(f, 2.0);
+    assert_eq!(g.len(), 0);
+    let (f, j) = <Chunked>::jacobian(residuals, &x, &());
+    assert_eq!(f, OVector::<f64, U2>::new(0.0, 1.0));
+    assert_eq!(j.shape(), (2, 0));
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "derive")]
+use approx::assert_relative_eq;
+use nalgebra::{SVector, U2};
+use num_dual::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, DualStruct)]
+struct Component<D> {
+    mass: D,
+    critical_point: (D, D),
+    name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, DualStruct)]
+struct Parameters<D> {
+    components: Vec<Component<D>>,
+    binary: [[D; 2]; 2],
+    scaling: Option<D>,
+    coefficients: HashMap<String, D>,
+    vector: SVector<D, 2>,
+    n: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, DualStruct)]
+enum Model<D> {
+    Ideal,
+    Virial(D, D),
+    Cubic { a: D, b: D, name: &'static str },
+}
+
+#[derive(Clone, Debug, PartialEq, DualStruct)]
+struct Bounded<D: DualNum<f64> + Copy, const N: usize> {
+    values: [D; N],
+}
+
+#[derive(Debug, PartialEq, Mappable)]
+struct State<D> {
+    pressure: D,
+    composition: Vec<D>,
+    phases: (D, Option<D>),
+    nested: Box<Output<D>>,
+    extra: HashMap<&'static str, [D; 2]>,
+    converged: bool,
+}
+
+#[derive(Debug, PartialEq, Mappable)]
+enum Output<D> {
+    Empty,
+    Scalar(D),
+    Fallible { value: Result<D, String> },
+}
+
+fn parameters() -> Parameters<f64> {
+    Parameters {
+        components: vec![Component {
+            mass: 2.0,
+            critical_point: (300.0, 5e6),
+            name: "water".into(),
+        }],
+        binary: [[0.0, 0.1], [0.1, 0.0]],
+        scaling: Some(1.5),
+        coefficients: HashMap::from([("c".into(), 3.0)]),
+        vector: SVector::from([1.0, 2.0]),
+        n: 4,
+    }
+}
+
+#[test]
+fn test_dual_struct_roundtrip() {
+    let p = parameters();
+    let d = Parameters::<Dual2_64>::from_inner(&p);
+    assert_eq!(d.components[0].mass, Dual2_64::from_re(2.0));
+    assert_eq!(d.components[0].name, "water");
+    assert_eq!(d.n, 4);
+    assert_eq!(d.re(), p);
+
+    let m = Model::Cubic {
+        a: 1.0,
+        b: 2.0,
+        name: "pr",
+    };
+    assert_eq!(Model::<Dual64>::from_inner(&m).re(), m);
+    let m = Model::Virial(1.0, 2.0);
+    assert_eq!(Model::<Dual64>::from_inner(&m).re(), m);
+    assert_eq!(
+        Model::<Dual64>::from_inner(&Model::Ideal).re(),
+        Model::Ideal
+    );
+
+    let b = Bounded {
+        values: [1.0, 2.0, 3.0],
+    };
+    assert_eq!(Bounded::<HyperDual64, 3>::from_inner(&b).re(), b);
+}
+
+#[test]
+fn test_dual_struct_nested() {
+    // Parameters that are dual numbers themselves are lifted correctly.
+    let p = Parameters::<Dual64>::from_inner(&parameters());
+    let p = Parameters {
+        scaling: Some(Dual64::new(1.5, 1.0)),
+        ..p
+    };
+    let q = Parameters::<Dual2<Dual64, f64>>::from_inner(&p);
+    assert_eq!(q.scaling.unwrap().re, Dual64::new(1.5, 1.0));
+    assert_eq!(q.re().scaling, Some(1.5));
+}
+
+fn objective<D: DualNum<f64> + Copy>(x: D, p: &Parameters<D>) -> D {
+    let c = &p.components[0];
+    let s = p.scaling.unwrap();
+    x * x * c.mass * s + c.critical_point.0 * p.binary[0][1] * x + p.coefficients["c"] + p.vector[1]
+}
+
+#[test]
+fn test_dual_struct_partial() {
+    let (f, df, d2f) = second_derivative(partial(objective, &parameters()), 2.0);
+    assert_relative_eq!(f, 12.0 + 60.0 + 3.0 + 2.0);
+    assert_relative_eq!(df, 12.0 + 30.0);
+    assert_relative_eq!(d2f, 6.0);
+}
+
+fn state<D: DualNum<f64> + Copy>(t: D) -> State<D> {
+    State {
+        pressure: t * t,
+        composition: vec![t.sin(), t.cos()],
+        phases: (t.exp(), None),
+        nested: Box::new(Output::Fallible { value: Ok(t * 3.0) }),
+        extra: HashMap::from([("x", [t, -t])]),
+        converged: true,
+    }
+}
+
+#[test]
+fn test_mappable() {
+    let s = first_derivative(state, 0.0);
+    assert_eq!(s.pressure, (0.0, 0.0));
+    assert_eq!(s.composition, vec![(0.0, 1.0), (1.0, 0.0)]);
+    assert_eq!(s.phases, ((1.0, 1.0), None));
+    assert_eq!(
+        *s.nested,
+        Output::Fallible {
+            value: Ok((0.0, 3.0))
+        }
+    );
+    assert_eq!(s.extra["x"], [(0.0, 1.0), (0.0, -1.0)]);
+    assert!(s.converged);
+
+    let o = first_derivative(|x: Dual64| Output::Scalar(x.powi(2)), 3.0);
+    assert_eq!(o, Output::Scalar((9.0, 6.0)));
+    let o = first_derivative(|_: Dual64| Output::<Dual64>::Empty, 3.0);
+    assert_eq!(o, Output::Empty);
+}
+
+#[test]
+fn test_mappable_gradients() {
+    let x = SVector::from([1.0, 2.0]);
+    let o = gradient(|x: SVector<DualVec64<U2>, 2>| Output::Scalar(x.dot(&x)), &x);
+    assert_eq!(o, Output::Scalar((5.0, SVector::from([2.0, 4.0]))));
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "ndarray")]
+use approx::assert_relative_eq;
+use nalgebra::{DVector, SVector};
+use ndarray::{Array1, Array2, arr1, arr2};
+use num_dual::*;
+
+#[test]
+fn test_first_derivative_array() {
+    let (f, df): (Array1<f64>, Array1<f64>) =
+        first_derivative(|x: Dual64| arr1(&[x.powi(2), x.sin()]), 2.0);
+    assert_eq!(f[0], 4.0);
+    assert_eq!(df[0], 4.0);
+    assert_relative_eq!(f[1], 2.0f64.sin());
+    assert_relative_eq!(df[1], 2.0f64.cos());
+}
+
+#[test]
+fn test_first_derivative_array2() {
+    let (f, df): (Array2<f64>, Array2<f64>) = first_derivative(
+        |x: Dual64| Array2::from_shape_fn((2, 2), |(i, j)| x.powi((i + j) as i32)),
+        2.0,
+    );
+    assert_eq!(f, arr2(&[[1.0, 2.0], [2.0, 4.0]]));
+    assert_eq!(df, arr2(&[[0.0, 1.0], [1.0, 4.0]]));
+
+    let res: Result<(Array1<f64>, Array1<f64>), ()> =
+        first_derivative(|x: Dual64| Ok(arr1(&[x.exp()])), 0.0);
+    assert_eq!(res, Ok((arr1(&[1.0]), arr1(&[1.0]))));
+}
+
+#[test]
+fn test_gradient_array() {
+    let x = SVector::from([2.0, 3.0]);
+    let res = gradient(
+        |x: SVector<DualSVec64<2>, 2>| arr1(&[x[0] * x[1], x[0].powi(2), DualSVec64::from(1.0)]),
+        &x,
+    );
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0], (6.0, SVector::from([3.0, 2.0])));
+    assert_eq!(res[1], (4.0, SVector::from([4.0, 0.0])));
+    assert_eq!(res[2], (1.0, SVector::from([0.0, 0.0])));
+
+    let x = DVector::from_vec(vec![2.0, 3.0]);
+    let res: Result<Array2<(f64, DVector<f64>)>, ()> = gradient(
+        |x: DVector<DualDVec64>| Ok(Array2::from_shape_fn((2, 2), |(i, j)| x[i].clone() * &x[j])),
+        &x,
+    );
+    let res = res.unwrap();
+    assert_eq!(res[(0, 1)], (6.0, DVector::from_vec(vec![3.0, 2.0])));
+    assert_eq!(res[(1, 1)], (9.0, DVector::from_vec(vec![0.0, 6.0])));
+}
+
+fn weighted<D: DualNum<f64> + Copy>(x: D, w: &Array1<D>) -> D {
+    w.iter().fold(D::zero(), |acc, &w| acc * x + w)
+}
+
+#[test]
+fn test_partial_array() {
+    let w = arr1(&[1.0, 2.0, 3.0]);
+    let (f, df) = first_derivative(partial(weighted, &w), 2.0);
+    assert_eq!(f, 11.0);
+    assert_eq!(df, 6.0);
+
+    let w = arr2(&[[1.0, 2.0], [3.0, 4.0]]);
+    let d = Array2::<Dual64>::from_inner(&w);
+    assert_eq!(d[(1, 0)], Dual64::from_re(3.0));
+    assert_eq!(d.re(), w);
+}
+
+fn rosenbrock<D: DualNum<f64> + Copy>(x: &[D]) -> D {
+    x.windows(2)
+        .map(|x| (D::one() - x[0]).powi(2) + (x[1] - x[0].powi(2)).powi(2) * 100.0)
+        .sum()
+}
+
+#[test]
+fn test_gradients_nd() {
+    let x = [0.5, 1.0, 1.5];
+    let (f, grad, hess) = GradientsNd::hessian(
+        |x: Array1<HyperDual64>, _: &()| rosenbrock(x.as_slice().unwrap()),
+        arr1(&x).view(),
+        &(),
+    );
+    let (f_na, grad_na, hess_na) = Gradients::hessian(
+        |x: DVector<HyperDual64>, _: &()| rosenbrock(x.as_slice()),
+        &DVector::from_row_slice(&x),
+        &(),
+    );
+    assert_eq!(f, f_na);
+    assert_eq!(grad.as_slice().unwrap(), grad_na.as_slice());
+    assert_eq!(
+        hess,
+        Array2::from_shape_fn((3, 3), |(i, j)| hess_na[(i, j)])
+    );
+
+    let (f, jac) = GradientsNd::jacobian(
+        |x: Array1<Dual64>, _: &()| arr1(&[rosenbrock(x.as_slice().unwrap()), x[0] * x[2]]),
+        arr1(&x).view(),
+        &(),
+    );
+    assert_eq!(f, arr1(&[f_na, 0.75]));
+    assert_eq!(jac.dim(), (2, 3));
+    assert_eq!(jac.row(0), grad);
+    assert_eq!(jac.row(1), arr1(&[1.5, 0.0, 0.5]));
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "rayon")]
+use approx::assert_relative_eq;
+use nalgebra::{
+    DVector, DefaultAllocator, Dim, Dyn, OVector, U2, allocator::Allocator, dvector, vector,
+};
+use num_dual::*;
+
+fn rosenbrock<D: DualNum<f64> + Copy, N: Dim>(x: OVector<D, N>, a: &D) -> D
+where
+    DefaultAllocator: Allocator<N>,
+{
+    (0..x.len() - 1)
+        .map(|i| (*a - x[i]).powi(2) + (x[i + 1] - x[i].powi(2)).powi(2) * 100.0)
+        .sum()
+}
+
+fn residuals<D: DualNum<f64> + Copy, N: Dim>(x: OVector<D, N>, _: &()) -> OVector<D, U2>
+where
+    DefaultAllocator: Allocator<N>,
+{
+    let s = x.iter().fold(D::zero(), |acc, &xi| acc + xi);
+    let p = x.iter().fold(D::one(), |acc, &xi| acc * xi.sin());
+    OVector::<D, U2>::new(s.powi(2), p.exp())
+}
+
+#[test]
+fn test_par_dyn() {
+    let x = dvector![0.3, -1.2, 0.8, 2.0, 0.1, -0.5, 1.4];
+
+    let (f, g) = Dyn::par_gradient(rosenbrock, &x, &1.5);
+    let (f_ref, g_ref) = Dyn::gradient(rosenbrock, &x, &1.5);
+    assert_eq!(f, f_ref);
+    assert_eq!(g, g_ref);
+
+    let (f, g, h) = Dyn::par_hessian(rosenbrock, &x, &1.5);
+    let (f_ref, g_ref, h_ref) = Dyn::hessian(rosenbrock, &x, &1.5);
+    assert_eq!(f, f_ref);
+    assert_eq!(g, g_ref);
+    assert_relative_eq!(h, h_ref, max_relative = 1e-14);
+    assert_eq!(h, h.transpose());
+
+    let fun = |x, y, a: &_| rosenbrock(x, a) * y;
+    let (f, gx, gy, h) = Dyn::par_partial_hessian(fun, &x, 2.0, &1.5);
+    let (f_ref, gx_ref, gy_ref, h_ref) = Dyn::partial_hessian(fun, &x, 2.0, &1.5);
+    assert_eq!(f, f_ref);
+    assert_eq!(gx, gx_ref);
+    assert_eq!(gy, gy_ref);
+    assert_eq!(h, h_ref);
+
+    let (f, j) = Dyn::par_jacobian(residuals, &x, &());
+    let (f_ref, j_ref) = Dyn::jacobian(residuals, &x, &());
+    assert_eq!(f, f_ref);
+    assert_eq!(j, j_ref);
+}
+
+#[test]
+fn test_par_static() {
+    let x = vector![0.3, -1.2, 0.8];
+    let (f, g, h) = ParallelGradients::par_hessian(rosenbrock, &x, &1.5);
+    let (f_ref, g_ref, h_ref) = Gradients::hessian(rosenbrock, &x, &1.5);
+    assert_eq!(f, f_ref);
+    assert_eq!(g, g_ref);
+    assert_eq!(h, h_ref);
+
+    let (f, j) = ParallelGradients::par_jacobian(residuals, &x, &());
+    let (f_ref, j_ref) = Gradients::jacobian(residuals, &x, &());
+    assert_eq!(f, f_ref);
+    assert_eq!(j, j_ref);
+}
+
+#[test]
+fn test_par_empty() {
+    let x = dvector![];
+    let (f, j) = Dyn::par_jacobian(residuals, &x, &());
+    assert_eq!(f, vector![0.0, 1f64.exp()]);
+    assert_eq!(j.shape(), (2, 0));
+
+    let constant = |_: DVector<Dual64>, a: &Dual64| a.powi(2);
+    let (f, g) = Dyn::par_gradient(constant, &x, &3.0);
+    assert_eq!(f, 9.0);
+    assert_eq!(g.len(), 0);
+
+    let constant = |_: DVector<HyperDual64>, a: &HyperDual64| a.powi(2);
+    let (f, g, h) = Dyn::par_hessian(constant, &x, &3.0);
+    assert_eq!(f, 9.0);
+    assert_eq!((g.len(), h.shape()), (0, (0, 0)));
+
+    let (f, g_x, g_y, h) =
+        Dyn::par_partial_hessian(|_, y: HyperDual64, a: &HyperDual64| y * a, &x, 2.0, &3.0);
+    assert_eq!((f, g_y), (6.0, 3.0));
+    assert_eq!((g_x.len(), h.len()), (0, 0));
+}
@@ -0,0 +1,39 @@
+use nalgebra::{DVector, SMatrix};
+use num_dual::*;
+
+#[test]
+fn test_parts_roundtrip_scalar() {
+    let x = HyperHyperDual64::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+    let parts = x.to_parts();
+    assert_eq!(parts.len(), HyperHyperDual64::PART_NAMES.len());
+    assert_eq!(parts[7], vec![8.0]);
+    assert_eq!(HyperHyperDual64::from_parts(&parts), x);
+}
+
+#[test]
+fn test_parts_roundtrip_dyn() {
+    let x = DualDVec64::new(
+        1.0,
+        Derivative::some(DVector::from_vec(vec![2.0, 3.0, 4.0])),
+    );
+    assert_eq!(x.part_shapes(), vec![vec![], vec![3]]);
+    assert_eq!(DualDVec64::from_parts(&x.to_parts()), x);
+
+    let y = DualDVec64::from_re(5.0);
+    assert_eq!(y.part_shapes(), vec![vec![], vec![0]]);
+    assert_eq!(DualDVec64::from_parts(&y.to_parts()), y);
+}
+
+#[test]
+fn test_split_parts_hyperdual_vec() {
+    let m = SMatrix::<HyperDualSVec64<2, 1>, 2, 1>::from_fn(|i, _| {
+        HyperDualSVec64::from_re(i as f64 + 1.0)
+            .derivative1(i)
+            .derivative2(0)
+            .powi(3)
+    });
+    let parts = split_parts(&m);
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0][0], SMatrix::<f64, 2, 1>::new(1.0, 8.0));
+    assert_eq!(from_parts::<_, f64, _, _>(&parts), m);
+}
@@ -0,0 +1,73 @@
+use approx::assert_relative_eq;
+use nalgebra::{DVector, dmatrix, dvector};
+use num_dual::DualNum;
+use num_dual::sensitivity::{sensitivity, sensitivity_second_order};
+use std::collections::HashMap;
+
+fn model<D: DualNum<f64> + Copy>(p: &HashMap<String, D>) -> DVector<D> {
+    let (a, b, c) = (p["a"], p["b"], p["c"]);
+    dvector![a * a * b / c, a + b]
+}
+
+fn parameters() -> HashMap<String, f64> {
+    HashMap::from([
+        ("c".to_string(), 4.0),
+        ("b".to_string(), 3.0),
+        ("a".to_string(), 2.0),
+    ])
+}
+
+#[test]
+fn test_first_order() {
+    let report = sensitivity(model, &parameters());
+    assert_eq!(report.parameters, ["a", "b", "c"]);
+    assert_eq!(report.values, dvector![2.0, 3.0, 4.0]);
+    assert_relative_eq!(report.outputs, dvector![3.0, 5.0]);
+    assert_relative_eq!(report.absolute, dmatrix![3.0, 1.0, -0.75; 1.0, 1.0, 0.0]);
+    assert_relative_eq!(report.relative, dmatrix![6.0, 3.0, -3.0; 2.0, 3.0, 0.0]);
+    assert_relative_eq!(report.log_log, dmatrix![2.0, 1.0, -1.0; 0.4, 0.6, 0.0]);
+    assert!(report.interactions.is_none());
+    assert!(report.normalized_interactions(0).is_none());
+
+    let ranking = report.ranking(1);
+    assert_eq!(ranking[0].0, "b");
+    assert_eq!(ranking[1].0, "a");
+    assert_eq!(ranking[2].0, "c");
+    let ranking = report.ranking(0);
+    assert_eq!(ranking[0], ("a", 2.0));
+}
+
+#[test]
+fn test_second_order() {
+    let first = sensitivity(model, &parameters());
+    let report = sensitivity_second_order(model, &parameters());
+    assert_relative_eq!(report.outputs, first.outputs);
+    assert_relative_eq!(report.absolute, first.absolute);
+    assert_relative_eq!(report.log_log, first.log_log);
+
+    let hessians = report.interactions.as_ref().unwrap();
+    assert_relative_eq!(
+        hessians[0],
+        dmatrix![1.5, 1.0, -0.75; 1.0, 0.0, -0.25; -0.75, -0.25, 0.375]
+    );
+    assert_relative_eq!(
+        hessians[1],
+        dmatrix![0.0, 0.0, 0.0; 0.0, 0.0, 0.0; 0.0, 0.0, 0.0]
+    );
+    assert_relative_eq!(
+        report.normalized_interactions(0).unwrap(),
+        dmatrix![2.0, 2.0, -2.0; 2.0, 0.0, -1.0; -2.0, -1.0, 2.0]
+    );
+}
+
+#[test]
+fn test_display() {
+    let report = sensitivity(model, &parameters());
+    let table = report.to_string();
+    let lines: Vec<_> = table.lines().collect();
+    assert_eq!(lines[0], "output 0: 3");
+    assert!(lines[1].starts_with("parameter"));
+    assert!(lines[2].starts_with("a "));
+    assert!(lines[2].ends_with("2.000000"));
+    assert_eq!(lines.len(), 11);
+}
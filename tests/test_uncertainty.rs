@@ -0,0 +1,69 @@
+use approx::assert_relative_eq;
+use nalgebra::{DVector, dvector, matrix, vector};
+use num_dual::DualNum;
+use num_dual::uncertainty::Uncertain;
+
+#[test]
+fn test_linear() {
+    let x = Uncertain::new(vector![1.0, 2.0], matrix![0.04, 0.01; 0.01, 0.09]);
+    let a = matrix![1.0, 2.0; 3.0, -1.0; 0.5, 0.5];
+    let expected = a * x.covariance * a.transpose();
+    let res = x.propagate(|x| vector![x[0] + x[1] * 2.0, x[0] * 3.0 - x[1], (x[0] + x[1]) * 0.5]);
+    assert_relative_eq!(res.mean, a * x.mean);
+    assert_relative_eq!(res.covariance, expected, max_relative = 1e-14);
+    let res = x.propagate_second_order(|x| {
+        vector![x[0] + x[1] * 2.0, x[0] * 3.0 - x[1], (x[0] + x[1]) * 0.5]
+    });
+    assert_relative_eq!(res.mean, a * x.mean, max_relative = 1e-14);
+    assert_relative_eq!(res.covariance, expected, max_relative = 1e-14);
+}
+
+#[test]
+fn test_second_order() {
+    let (mx, my, sx, sy) = (3.0f64, 2.0f64, 0.1f64, 0.2f64);
+    let x = Uncertain::independent(vector![mx, my], vector![sx, sy]);
+
+    let res = x.propagate(|x| DVector::from_vec(vec![x[0] * x[1], x[0].powi(2)]));
+    assert_relative_eq!(res.mean, dvector![mx * my, mx * mx]);
+    assert_relative_eq!(
+        res.covariance[(0, 0)],
+        (my * sx).powi(2) + (mx * sy).powi(2)
+    );
+    assert_relative_eq!(res.covariance[(1, 1)], (2.0 * mx * sx).powi(2));
+
+    // exact moments of normally distributed variables
+    let res = x.propagate_second_order(|x| DVector::from_vec(vec![x[0] * x[1], x[0].powi(2)]));
+    assert_relative_eq!(res.mean, dvector![mx * my, mx * mx + sx * sx]);
+    assert_relative_eq!(
+        res.covariance[(0, 0)],
+        (my * sx).powi(2) + (mx * sy).powi(2) + (sx * sy).powi(2),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        res.covariance[(1, 1)],
+        4.0 * (mx * sx).powi(2) + 2.0 * sx.powi(4),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        res.covariance[(0, 1)],
+        2.0 * mx * my * sx * sx,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_correlation() {
+    let x = Uncertain::independent(vector![1.0, 2.0, 5.0], vector![0.3, 0.3, 0.0]);
+    assert_eq!(x.std_dev(), vector![0.3, 0.3, 0.0]);
+    let res = x.propagate(|x| vector![x[0] + x[1], x[0] - x[1], x[0] * 2.0, x[2].sin()]);
+    let corr = res.correlation();
+    assert_relative_eq!(corr[(0, 1)], 0.0);
+    assert_relative_eq!(corr[(0, 2)], 0.5f64.sqrt());
+    assert_relative_eq!(corr[(1, 2)], 0.5f64.sqrt());
+    assert_eq!(corr[(3, 0)], 0.0);
+    assert_eq!(corr.diagonal(), vector![1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(res.std_dev()[3], 0.0);
+
+    let x = Uncertain::new(vector![1.0, 2.0], matrix![4.0, -3.0; -3.0, 9.0]);
+    assert_relative_eq!(x.correlation(), matrix![1.0, -0.5; -0.5, 1.0]);
+}